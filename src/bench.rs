@@ -0,0 +1,153 @@
+//! Timing of the registered solvers.
+//!
+//! Parsing and each part are timed separately: the input is parsed once per
+//! iteration, and each part is then solved repeatedly from a single parsed
+//! value. Reports serialize to JSON so runs can be compared by other tools.
+//! Numbers from debug builds are not representative, so run with `--release`.
+
+use crate::{AocError, Day};
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Summary statistics over the samples of one measurement, in nanoseconds.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Timing {
+    pub min_ns: u64,
+    pub median_ns: u64,
+    pub mean_ns: u64,
+}
+
+impl Timing {
+    /// Summarises `samples`, which must not be empty.
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples
+            .iter()
+            .map(|d| d.as_nanos() as u64)
+            .collect::<Vec<_>>();
+        nanos.sort_unstable();
+        Timing {
+            min_ns: nanos[0],
+            median_ns: nanos[nanos.len() / 2],
+            mean_ns: nanos.iter().sum::<u64>() / nanos.len() as u64,
+        }
+    }
+
+    pub fn median(&self) -> Duration {
+        Duration::from_nanos(self.median_ns)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct PartReport {
+    pub part: u32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub answer: Option<String>,
+    /// Set if the part failed, in which case it isn't timed.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub solve: Option<Timing>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct DayReport {
+    pub day: u32,
+    pub input: String,
+    pub parse: Timing,
+    pub parts: Vec<PartReport>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Report {
+    pub iterations: usize,
+    pub days: Vec<DayReport>,
+}
+
+/// Times `day` against `contents`, which was read from `input`. Fails only if
+/// the input can't be parsed; errors from the parts are recorded in the
+/// report.
+pub fn bench_day(
+    day: &Day,
+    input: &str,
+    contents: &str,
+    iterations: usize,
+) -> Result<DayReport, AocError> {
+    let iterations = iterations.max(1);
+    let mut parse_samples = Vec::with_capacity(iterations);
+    let mut solution = None;
+    for _ in 0..iterations {
+        let start = Instant::now();
+        let parsed = (day.parse)(contents)?;
+        parse_samples.push(start.elapsed());
+        solution = Some(parsed);
+    }
+    let solution = solution.unwrap();
+    let parts = (1..=day.parts)
+        .map(|part| {
+            let solve = || match part {
+                1 => solution.part1_answer(),
+                _ => solution.part2_answer(),
+            };
+            let mut samples = Vec::with_capacity(iterations);
+            let mut result = Ok(String::new());
+            for _ in 0..iterations {
+                let start = Instant::now();
+                let answer = solve();
+                samples.push(start.elapsed());
+                match answer {
+                    Ok(answer) => result = Ok(answer.to_string()),
+                    Err(e) => {
+                        result = Err(e.to_string());
+                        break;
+                    }
+                }
+            }
+            match result {
+                Ok(answer) => PartReport {
+                    part,
+                    answer: Some(answer),
+                    error: None,
+                    solve: Some(Timing::from_samples(&samples)),
+                },
+                Err(e) => PartReport {
+                    part,
+                    answer: None,
+                    error: Some(e),
+                    solve: None,
+                },
+            }
+        })
+        .collect();
+    Ok(DayReport {
+        day: day.day,
+        input: input.to_string(),
+        parse: Timing::from_samples(&parse_samples),
+        parts,
+    })
+}
+
+#[test]
+fn bench_test() {
+    let day = crate::get_day(1).unwrap();
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    let report = bench_day(day, "example", input, 3).unwrap();
+    assert_eq!(1, report.day);
+    assert_eq!(Some("24000"), report.parts[0].answer.as_deref());
+    assert_eq!(Some("45000"), report.parts[1].answer.as_deref());
+    assert!(report.parts.iter().all(|p| p.solve.is_some()));
+    assert!(report.parse.min_ns <= report.parse.median_ns);
+
+    let report = bench_day(day, "example", "1\n\n2\n", 3).unwrap();
+    assert!(report.parts[1].error.is_some());
+    assert_eq!(None, report.parts[1].solve);
+
+    let json = serde_json::to_value(Report {
+        iterations: 3,
+        days: vec![report],
+    })
+    .unwrap();
+    assert_eq!(1, json["days"][0]["day"]);
+    assert!(json["days"][0]["parse"]["median_ns"].is_u64());
+    assert!(json["days"][0]["parts"][1]["error"].is_string());
+    assert!(json["days"][0]["parts"][1].get("solve").is_none());
+}
@@ -0,0 +1,23 @@
+//! Helpers shared between days.
+
+/// Splits the input into blank-line separated blocks, along with the (1-based)
+/// line number each block starts on.
+pub fn split_blocks(input: &str) -> Vec<(usize, &str)> {
+    let mut line = 1;
+    input
+        .split("\n\n")
+        .map(|block| {
+            let start = line;
+            line += block.lines().count() + 1;
+            (start, block)
+        })
+        .collect()
+}
+
+#[test]
+fn split_blocks_test() {
+    assert_eq!(
+        vec![(1, "a\nb"), (4, "c"), (6, "d\n")],
+        split_blocks("a\nb\n\nc\n\nd\n")
+    );
+}
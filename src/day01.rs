@@ -0,0 +1,536 @@
+use crate::{AocError, Solution};
+use serde::Serialize;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+
+/// The total calories carried by each elf, in input order.
+pub struct Day1 {
+    pub totals: Vec<u64>,
+}
+
+impl Solution for Day1 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let totals = elf_totals(input.as_bytes()).collect::<Result<_, _>>()?;
+        Ok(Day1 { totals })
+    }
+
+    fn part1(&self) -> Result<u64, AocError> {
+        self.sum_of_top(1)
+    }
+
+    fn part2(&self) -> Result<u64, AocError> {
+        self.sum_of_top(3)
+    }
+}
+
+impl Day1 {
+    /// Like [`top_n_elves`], for the already parsed totals.
+    pub fn top_n(&self, n: usize) -> Result<Vec<(usize, u64)>, AocError> {
+        top_n(self.totals.iter().map(|&total| Ok(total)), n)
+    }
+
+    fn sum_of_top(&self, n: usize) -> Result<u64, AocError> {
+        sum_of_top(self.top_n(n)?, n)
+    }
+}
+
+/// The calories carried between them by the `n` elves in `top`, checking
+/// there were that many elves and that the total fits in a `u64`.
+fn sum_of_top(top: Vec<(usize, u64)>, n: usize) -> Result<u64, AocError> {
+    if top.is_empty() {
+        return Err(AocError::invalid(1, "no elves in input"));
+    }
+    if top.len() < n {
+        return Err(AocError::invalid(
+            1,
+            format!("fewer than {} elves in input", n),
+        ));
+    }
+    top.iter()
+        .try_fold(0_u64, |sum, &(_, total)| sum.checked_add(total))
+        .ok_or_else(|| AocError::invalid(1, "the top elves' calorie total overflows"))
+}
+
+/// Reads the elves' inventories one elf at a time, yielding the calories of
+/// each item an elf carries. Only the current line and elf are held in
+/// memory, so inputs of any size can be processed.
+///
+/// Whitespace around each line (including the `\r` of a CRLF line ending) is
+/// ignored, and any run of blank lines separates two elves, so blank lines at
+/// the start or end of the input don't produce empty inventories. Iteration
+/// stops after the first error.
+pub struct Inventories<R> {
+    reader: R,
+    buf: String,
+    line: usize,
+    done: bool,
+}
+
+impl<R: BufRead> Inventories<R> {
+    pub fn new(reader: R) -> Self {
+        Inventories {
+            reader,
+            buf: String::new(),
+            line: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Inventories<R> {
+    type Item = Result<Vec<u64>, AocError>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut items = Vec::new();
+        while !self.done {
+            self.buf.clear();
+            match self.reader.read_line(&mut self.buf) {
+                Ok(0) => self.done = true,
+                Ok(_) => {
+                    self.line += 1;
+                    let line = self.buf.trim();
+                    if line.is_empty() {
+                        if items.is_empty() {
+                            continue;
+                        }
+                        break;
+                    }
+                    match line.parse::<u64>() {
+                        Ok(calories) => items.push(calories),
+                        Err(e) => {
+                            self.done = true;
+                            let reason = format!("invalid calories: {}", e);
+                            return Some(Err(AocError::parse(1, self.line, line, reason)));
+                        }
+                    }
+                }
+                Err(e) => {
+                    self.done = true;
+                    return Some(Err(e.into()));
+                }
+            }
+        }
+        if items.is_empty() {
+            None
+        } else {
+            Some(Ok(items))
+        }
+    }
+}
+
+/// The total calories carried by each elf, read one elf at a time.
+fn elf_totals(reader: impl BufRead) -> impl Iterator<Item = Result<u64, AocError>> {
+    Inventories::new(reader).map(|items| {
+        items?
+            .iter()
+            .try_fold(0_u64, |sum, &item| sum.checked_add(item))
+            .ok_or_else(|| AocError::invalid(1, "calorie total overflows"))
+    })
+}
+
+/// The (up to) `n` elves carrying the most calories, as `(index, total)`
+/// pairs from the most calories down. Elves are indexed from 0 in input
+/// order, and ties go to the earlier elf.
+///
+/// Only the best `n` elves seen so far are kept while reading the input, so
+/// this doesn't hold every elf's total at once.
+pub fn top_n_elves(input: &str, n: usize) -> Result<Vec<(usize, u64)>, AocError> {
+    top_n(elf_totals(input.as_bytes()), n)
+}
+
+fn top_n(
+    totals: impl IntoIterator<Item = Result<u64, AocError>>,
+    n: usize,
+) -> Result<Vec<(usize, u64)>, AocError> {
+    // A min-heap, so the weakest of the best so far is the one to go. Later
+    // elves compare as smaller, so lose ties.
+    let mut heap = BinaryHeap::new();
+    for (index, total) in totals.into_iter().enumerate() {
+        heap.push(Reverse((total?, Reverse(index))));
+        if heap.len() > n {
+            heap.pop();
+        }
+    }
+    Ok(heap
+        .into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| (index, total))
+        .collect())
+}
+
+/// Summary statistics over the calories each elf is carrying.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ElfInventory {
+    /// Each elf's total, in input order.
+    totals: Vec<u64>,
+    /// The same totals, smallest first.
+    sorted: Vec<u64>,
+}
+
+/// A range of totals in a histogram, and how many elves' totals fall in it.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub from: u64,
+    /// Inclusive.
+    pub to: u64,
+    pub count: usize,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Serialize)]
+pub struct Percentile {
+    pub percentile: f64,
+    pub calories: f64,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+pub struct ElfTotal {
+    pub elf: usize,
+    pub calories: u64,
+}
+
+/// Everything [`ElfInventory`] can say about the elves, in a form that
+/// serializes to JSON.
+#[derive(Clone, Debug, PartialEq, Serialize)]
+pub struct InventoryReport {
+    pub count: usize,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    pub percentiles: Vec<Percentile>,
+    pub histogram: Vec<Bucket>,
+    pub totals: Vec<ElfTotal>,
+}
+
+impl ElfInventory {
+    pub fn new(totals: Vec<u64>) -> Self {
+        let mut sorted = totals.clone();
+        sorted.sort_unstable();
+        ElfInventory { totals, sorted }
+    }
+
+    pub fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(ElfInventory::new(
+            elf_totals(input.as_bytes()).collect::<Result<_, _>>()?,
+        ))
+    }
+
+    /// Reads the inventories with [`Inventories`], so only the totals are
+    /// kept in memory.
+    pub fn from_reader(reader: impl Read) -> Result<Self, AocError> {
+        Ok(ElfInventory::new(
+            elf_totals(BufReader::new(reader)).collect::<Result<_, _>>()?,
+        ))
+    }
+
+    pub fn count(&self) -> usize {
+        self.totals.len()
+    }
+
+    /// Each elf's total calories along with its index (from 0) in the input.
+    pub fn totals(&self) -> impl Iterator<Item = (usize, u64)> + '_ {
+        self.totals.iter().copied().enumerate()
+    }
+
+    /// `None` if there are no elves.
+    pub fn mean(&self) -> Option<f64> {
+        if self.totals.is_empty() {
+            return None;
+        }
+        Some(self.totals.iter().map(|&t| t as f64).sum::<f64>() / self.count() as f64)
+    }
+
+    /// `None` if there are no elves.
+    pub fn median(&self) -> Option<f64> {
+        self.percentile(50.0)
+    }
+
+    /// The total below which `p` percent of the elves' totals fall,
+    /// interpolating linearly between the two nearest totals. `None` if there
+    /// are no elves or `p` isn't between 0 and 100.
+    pub fn percentile(&self, p: f64) -> Option<f64> {
+        if self.sorted.is_empty() || !(0.0..=100.0).contains(&p) {
+            return None;
+        }
+        let rank = p / 100.0 * (self.sorted.len() - 1) as f64;
+        let (lower, upper) = (rank.floor() as usize, rank.ceil() as usize);
+        let (a, b) = (self.sorted[lower] as f64, self.sorted[upper] as f64);
+        Some(a + (b - a) * (rank - lower as f64))
+    }
+
+    /// Splits the range from the smallest to the largest total into (at most)
+    /// `buckets` equally sized buckets, and counts the elves in each. The last
+    /// bucket stops at `u64::MAX` if a full-sized one would go past it.
+    pub fn buckets(&self, buckets: usize) -> Vec<Bucket> {
+        let (Some(&min), Some(&max)) = (self.sorted.first(), self.sorted.last()) else {
+            return Vec::new();
+        };
+        // The range can be a whole u64 wide, so the sizes are worked out in
+        // u128s
+        let size = (u128::from(max - min) + 1).div_ceil(buckets.max(1) as u128);
+        let to = |from: u128| u64::try_from(from + size - 1).unwrap_or(u64::MAX);
+        let mut res = Vec::<Bucket>::new();
+        for &total in &self.sorted {
+            let from = u128::from(min) + u128::from(total - min) / size * size;
+            match res.last_mut() {
+                Some(bucket) if u128::from(bucket.from) == from => bucket.count += 1,
+                _ => {
+                    // Include the empty buckets in between
+                    let mut next = res.last().map_or(min.into(), |b| u128::from(b.to) + 1);
+                    while next < from {
+                        res.push(Bucket {
+                            from: next as u64,
+                            to: to(next),
+                            count: 0,
+                        });
+                        next += size;
+                    }
+                    res.push(Bucket {
+                        from: from as u64,
+                        to: to(from),
+                        count: 1,
+                    });
+                }
+            }
+        }
+        res
+    }
+
+    /// [`ElfInventory::buckets`] drawn as a bar chart, one line per bucket,
+    /// with the longest bar `width` characters long.
+    pub fn histogram(&self, buckets: usize, width: usize) -> String {
+        let buckets = self.buckets(buckets);
+        let most = buckets.iter().map(|b| b.count).max().unwrap_or(0);
+        let digits = buckets.last().map_or(1, |b| b.to.to_string().len());
+        let mut res = String::new();
+        for bucket in buckets {
+            let bar = (bucket.count * width).div_ceil(most.max(1));
+            res.push_str(&format!(
+                "{:>digits$}-{:<digits$} |{} {}\n",
+                bucket.from,
+                bucket.to,
+                "#".repeat(bar),
+                bucket.count,
+            ));
+        }
+        res
+    }
+
+    pub fn report(&self, percentiles: &[f64], buckets: usize) -> InventoryReport {
+        InventoryReport {
+            count: self.count(),
+            mean: self.mean(),
+            median: self.median(),
+            percentiles: percentiles
+                .iter()
+                .filter_map(|&p| {
+                    Some(Percentile {
+                        percentile: p,
+                        calories: self.percentile(p)?,
+                    })
+                })
+                .collect(),
+            histogram: self.buckets(buckets),
+            totals: self
+                .totals()
+                .map(|(elf, calories)| ElfTotal { elf, calories })
+                .collect(),
+        }
+    }
+}
+
+pub fn day1_part1(filename: &str) -> Result<u64, AocError> {
+    day1_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day1_part1_from_str(input: &str) -> Result<u64, AocError> {
+    Day1::parse(input)?.part1()
+}
+
+pub fn day1_part1_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    sum_of_top(top_n(elf_totals(BufReader::new(reader)), 1)?, 1)
+}
+
+pub fn day1_part2(filename: &str) -> Result<u64, AocError> {
+    day1_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day1_part2_from_str(input: &str) -> Result<u64, AocError> {
+    Day1::parse(input)?.part2()
+}
+
+pub fn day1_part2_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    sum_of_top(top_n(elf_totals(BufReader::new(reader)), 3)?, 3)
+}
+
+#[test]
+fn day1_test() {
+    assert_eq!(69693, day1_part1("inputs/1.txt").unwrap());
+    assert_eq!(200945, day1_part2("inputs/1.txt").unwrap());
+}
+
+#[test]
+fn day1_from_str_test() {
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    assert_eq!(24000, day1_part1_from_str(input).unwrap());
+    assert_eq!(45000, day1_part2_from_str(input).unwrap());
+    assert_eq!(45000, day1_part2_from_reader(input.as_bytes()).unwrap());
+
+    assert_eq!(
+        vec![(3, 24000), (2, 11000), (4, 10000)],
+        top_n_elves(input, 3).unwrap()
+    );
+    assert_eq!(vec![(3, 24000)], top_n_elves(input, 1).unwrap());
+    assert_eq!(5, top_n_elves(input, 10).unwrap().len());
+    assert!(top_n_elves(input, 0).unwrap().is_empty());
+    // The heap only grows with the elves, however many are asked for
+    assert_eq!(5, top_n_elves(input, 1 << 40).unwrap().len());
+    assert_eq!(5, top_n_elves(input, usize::MAX).unwrap().len());
+    assert_eq!(
+        vec![(0, 5), (2, 5), (1, 3)],
+        top_n_elves("5\n\n3\n\n5\n\n1\n", 3).unwrap()
+    );
+    assert!(top_n_elves("1\n\nx\n", 1).is_err());
+
+    let day = Day1 {
+        totals: vec![u64::MAX, 1, 1],
+    };
+    assert_eq!(u64::MAX, day.part1().unwrap());
+    assert!(day.part2().is_err());
+    assert_eq!(vec![(0, u64::MAX)], day.top_n(1).unwrap());
+    assert!(day1_part2_from_str("1\n\n2\n").is_err());
+}
+
+#[test]
+fn day1_inventories_test() {
+    let items = |input: &str| {
+        Inventories::new(input.as_bytes())
+            .collect::<Result<Vec<_>, _>>()
+            .unwrap()
+    };
+    let expected = vec![vec![1000, 2000], vec![3000]];
+    assert_eq!(expected, items("1000\n2000\n\n3000\n"));
+    assert_eq!(expected, items("1000\r\n2000\r\n\r\n3000\r\n"));
+    assert_eq!(expected, items("\n 1000 \n2000\t\n  \n\n3000\n\n\n"));
+    assert_eq!(expected, items("1000\n2000\n\n3000"));
+    assert!(items("").is_empty());
+
+    let mut inventories = Inventories::new("1\n\n2\nx\n3\n".as_bytes());
+    assert_eq!(vec![1], inventories.next().unwrap().unwrap());
+    assert!(matches!(
+        inventories.next(),
+        Some(Err(AocError::Parse { line: 4, .. }))
+    ));
+    assert!(inventories.next().is_none());
+
+    let generated = "100\r\n200\r\n\r\n".repeat(100_000) + "1000\r\n";
+    assert_eq!(1000, day1_part1_from_reader(generated.as_bytes()).unwrap());
+    assert_eq!(1600, day1_part2_from_reader(generated.as_bytes()).unwrap());
+    assert!(day1_part1_from_reader("".as_bytes()).is_err());
+    let max = format!("{}\n1\n", u64::MAX);
+    assert!(day1_part1_from_str(&max).is_err());
+    assert!(day1_part1_from_reader(max.as_bytes()).is_err());
+    assert_eq!(
+        u64::MAX,
+        day1_part1_from_str(&format!("{}\n\n1\n", u64::MAX)).unwrap()
+    );
+}
+
+#[test]
+fn elf_inventory_test() {
+    let input = "1000\n2000\n3000\n\n4000\n\n5000\n6000\n\n7000\n8000\n9000\n\n10000\n";
+    let inventory = ElfInventory::parse(input).unwrap();
+    assert_eq!(
+        inventory,
+        ElfInventory::from_reader(input.as_bytes()).unwrap()
+    );
+    assert_eq!(5, inventory.count());
+    assert_eq!(
+        vec![(0, 6000), (1, 4000), (2, 11000), (3, 24000), (4, 10000)],
+        inventory.totals().collect::<Vec<_>>()
+    );
+    assert_eq!(Some(11000.0), inventory.mean());
+    assert_eq!(Some(10000.0), inventory.median());
+    assert_eq!(Some(4000.0), inventory.percentile(0.0));
+    assert_eq!(Some(24000.0), inventory.percentile(100.0));
+    assert_eq!(Some(5000.0), inventory.percentile(12.5));
+    assert_eq!(None, inventory.percentile(101.0));
+    assert_eq!(
+        vec![
+            Bucket {
+                from: 4000,
+                to: 9000,
+                count: 2
+            },
+            Bucket {
+                from: 9001,
+                to: 14001,
+                count: 2
+            },
+            Bucket {
+                from: 14002,
+                to: 19002,
+                count: 0
+            },
+            Bucket {
+                from: 19003,
+                to: 24003,
+                count: 1
+            },
+        ],
+        inventory.buckets(4)
+    );
+    assert_eq!(
+        " 4000-9000  |#### 2\n 9001-14001 |#### 2\n14002-19002 | 0\n19003-24003 |## 1\n",
+        inventory.histogram(4, 4)
+    );
+
+    let report = inventory.report(&[50.0, 200.0], 4);
+    assert_eq!(1, report.percentiles.len());
+    assert_eq!(5, report.totals.len());
+    let json = serde_json::to_value(&report).unwrap();
+    assert_eq!(11000.0, json["mean"]);
+    assert_eq!(24000, json["totals"][3]["calories"]);
+
+    let empty = ElfInventory::parse("").unwrap();
+    assert_eq!(0, empty.count());
+    assert_eq!(None, empty.mean());
+    assert_eq!(None, empty.median());
+    assert!(empty.buckets(10).is_empty());
+
+    // Totals spanning every u64
+    let wide = ElfInventory::new(vec![u64::MAX, 0]);
+    assert_eq!(
+        vec![Bucket {
+            from: 0,
+            to: u64::MAX,
+            count: 2
+        }],
+        wide.buckets(1)
+    );
+    let third = u64::MAX / 3 + 1;
+    assert_eq!(
+        vec![
+            Bucket {
+                from: 0,
+                to: third - 1,
+                count: 1
+            },
+            Bucket {
+                from: third,
+                to: 2 * third - 1,
+                count: 0
+            },
+            Bucket {
+                from: 2 * third,
+                to: u64::MAX,
+                count: 1
+            },
+        ],
+        wide.buckets(3)
+    );
+    assert_eq!("", empty.histogram(10, 40));
+}
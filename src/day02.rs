@@ -0,0 +1,238 @@
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Lose,
+    Draw,
+    Win,
+}
+
+/// A hand game where the shapes form a cycle: with `n` shapes (`n` odd), each
+/// shape beats the `(n - 1) / 2` shapes before it in the cycle and loses to
+/// the `(n - 1) / 2` after it. Shapes are identified by their position in the
+/// cycle.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Game {
+    /// The score for playing each shape.
+    shape_scores: Vec<u64>,
+    /// The score for losing, drawing and winning, in that order.
+    outcome_scores: [u64; 3],
+}
+
+impl Game {
+    /// A game with one shape per entry of `shape_scores`. `None` unless there
+    /// is an odd number of shapes, as otherwise some pairs of shapes would
+    /// each beat the other.
+    pub fn new(shape_scores: Vec<u64>, outcome_scores: [u64; 3]) -> Option<Self> {
+        if shape_scores.len().is_multiple_of(2) {
+            return None;
+        }
+        Some(Game {
+            shape_scores,
+            outcome_scores,
+        })
+    }
+
+    /// Rock, paper and scissors, scoring 1, 2 and 3, with 0 for a loss, 3 for
+    /// a draw and 6 for a win.
+    pub fn rock_paper_scissors() -> Self {
+        Game::new(vec![1, 2, 3], [0, 3, 6]).unwrap()
+    }
+
+    /// Rock, Spock, paper, lizard and scissors, in that order, scoring 1 to 5
+    /// and with the same outcome scores as rock paper scissors.
+    pub fn rock_paper_scissors_lizard_spock() -> Self {
+        Game::new(vec![1, 2, 3, 4, 5], [0, 3, 6]).unwrap()
+    }
+
+    pub fn shapes(&self) -> usize {
+        self.shape_scores.len()
+    }
+
+    /// The outcome for the player when they play `player` against `opponent`.
+    pub fn outcome(&self, opponent: usize, player: usize) -> Outcome {
+        let n = self.shapes();
+        match (player + n - opponent) % n {
+            0 => Outcome::Draw,
+            ahead if ahead <= n / 2 => Outcome::Win,
+            _ => Outcome::Lose,
+        }
+    }
+
+    /// A shape to play against `opponent` to get `outcome`.
+    pub fn response(&self, opponent: usize, outcome: Outcome) -> usize {
+        let n = self.shapes();
+        match outcome {
+            Outcome::Lose => (opponent + n - 1) % n,
+            Outcome::Draw => opponent,
+            Outcome::Win => (opponent + 1) % n,
+        }
+    }
+
+    /// The player's score for a round.
+    pub fn score(&self, opponent: usize, player: usize) -> u64 {
+        let outcome = self.outcome(opponent, player) as usize;
+        self.shape_scores[player] + self.outcome_scores[outcome]
+    }
+}
+
+/// How to read the second column of the strategy guide.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Interpretation {
+    /// `X`, `Y` and `Z` are the shape to play.
+    Shape,
+    /// `X`, `Y` and `Z` mean lose, draw and win.
+    Outcome,
+}
+
+/// Each round's opponent shape (`A`, `B` or `C`) and second column code (`X`,
+/// `Y` or `Z`), both as 0, 1 or 2.
+pub struct Day2 {
+    pub game: Game,
+    pub rounds: Vec<(usize, usize)>,
+}
+
+impl Solution for Day2 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let rounds = input
+            .lines()
+            .enumerate()
+            .map(|(i, f)| match f.chars().collect::<Vec<_>>()[..] {
+                [opp @ 'A'..='C', ' ', code @ 'X'..='Z'] => {
+                    Ok((opp as usize - 'A' as usize, code as usize - 'X' as usize))
+                }
+                [_, ' ', 'X'..='Z'] => {
+                    Err(AocError::parse(2, i + 1, f, "opponent must be A, B or C"))
+                }
+                [_, ' ', _] => Err(AocError::parse(2, i + 1, f, "response must be X, Y or Z")),
+                _ => Err(AocError::parse(2, i + 1, f, "expected two codes")),
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Day2 {
+            game: Game::rock_paper_scissors(),
+            rounds,
+        })
+    }
+
+    fn part1(&self) -> Result<u64, AocError> {
+        Ok(self.total_score(Interpretation::Shape))
+    }
+
+    fn part2(&self) -> Result<u64, AocError> {
+        Ok(self.total_score(Interpretation::Outcome))
+    }
+}
+
+impl Day2 {
+    /// The total score from following the strategy guide, reading it
+    /// according to `interpretation`.
+    pub fn total_score(&self, interpretation: Interpretation) -> u64 {
+        self.rounds
+            .iter()
+            .map(|&(opp, code)| {
+                let player = match interpretation {
+                    Interpretation::Shape => code,
+                    Interpretation::Outcome => {
+                        let outcome = [Outcome::Lose, Outcome::Draw, Outcome::Win][code];
+                        self.game.response(opp, outcome)
+                    }
+                };
+                self.game.score(opp, player)
+            })
+            .sum()
+    }
+}
+
+pub fn day2_part1(filename: &str) -> Result<u64, AocError> {
+    day2_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day2_part1_from_str(input: &str) -> Result<u64, AocError> {
+    Day2::parse(input)?.part1()
+}
+
+pub fn day2_part1_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day2::parse_reader(reader)?.part1()
+}
+
+pub fn day2_part2(filename: &str) -> Result<u64, AocError> {
+    day2_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day2_part2_from_str(input: &str) -> Result<u64, AocError> {
+    Day2::parse(input)?.part2()
+}
+
+pub fn day2_part2_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day2::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day2_test() {
+    assert_eq!(11449, day2_part1("inputs/2.txt").unwrap());
+    assert_eq!(13187, day2_part2("inputs/2.txt").unwrap());
+}
+
+#[test]
+fn day2_parse_error_test() {
+    match Day2::parse("A X\nB Q\n") {
+        Err(AocError::Parse {
+            day, line, text, ..
+        }) => {
+            assert_eq!(2, day);
+            assert_eq!(2, line);
+            assert_eq!("B Q", text);
+        }
+        _ => panic!("expected a parse error"),
+    }
+}
+
+#[test]
+fn day2_game_test() {
+    let input = "A Y\nB X\nC Z\n";
+    assert_eq!(15, day2_part1_from_str(input).unwrap());
+    assert_eq!(12, day2_part2_from_str(input).unwrap());
+
+    let (rock, paper, scissors) = (0, 1, 2);
+    let rps = Game::rock_paper_scissors();
+    assert_eq!(Outcome::Win, rps.outcome(rock, paper));
+    assert_eq!(Outcome::Lose, rps.outcome(rock, scissors));
+    assert_eq!(Outcome::Draw, rps.outcome(paper, paper));
+    assert_eq!(Outcome::Win, rps.outcome(scissors, rock));
+    assert_eq!(7, rps.score(scissors, rock));
+
+    let (rock, spock, paper, lizard, scissors) = (0, 1, 2, 3, 4);
+    let rpsls = Game::rock_paper_scissors_lizard_spock();
+    for (winner, loser) in [
+        (scissors, paper),
+        (paper, rock),
+        (rock, lizard),
+        (lizard, spock),
+        (spock, scissors),
+        (scissors, lizard),
+        (lizard, paper),
+        (paper, spock),
+        (spock, rock),
+        (rock, scissors),
+    ] {
+        assert_eq!(Outcome::Win, rpsls.outcome(loser, winner));
+        assert_eq!(Outcome::Lose, rpsls.outcome(winner, loser));
+    }
+    // Every shape beats and loses to half of the others
+    let game = Game::new(vec![1; 7], [0, 1, 2]).unwrap();
+    for opp in 0..7 {
+        for outcome in [Outcome::Lose, Outcome::Draw, Outcome::Win] {
+            assert_eq!(outcome, game.outcome(opp, game.response(opp, outcome)));
+        }
+        let wins = (0..7)
+            .filter(|&p| game.outcome(opp, p) == Outcome::Win)
+            .count();
+        assert_eq!(3, wins);
+    }
+    assert_eq!(None, Game::new(vec![1, 2, 3, 4], [0, 3, 6]));
+}
@@ -0,0 +1,108 @@
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day3 {
+    pub rucksacks: Vec<String>,
+}
+
+impl Solution for Day3 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let rucksacks = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                if !l.chars().all(|c| c.is_ascii_alphabetic()) {
+                    Err(AocError::parse(3, i + 1, l, "items must be letters"))
+                } else if !l.len().is_multiple_of(2) {
+                    Err(AocError::parse(3, i + 1, l, "compartments differ in size"))
+                } else {
+                    Ok(l.to_string())
+                }
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Day3 { rucksacks })
+    }
+
+    fn part1(&self) -> Result<u32, AocError> {
+        self.rucksacks
+            .iter()
+            .map(|l| {
+                let len = l.len();
+                let fst = &l[0..len / 2].chars().collect::<HashSet<char>>();
+                let snd = &l[len / 2..].chars().collect::<HashSet<char>>();
+                let chr = *fst
+                    .intersection(snd)
+                    .next()
+                    .ok_or_else(|| AocError::invalid(3, format!("no shared item in {}", l)))?
+                    as u32;
+                Ok(priority(chr))
+            })
+            .sum()
+    }
+
+    fn part2(&self) -> Result<u32, AocError> {
+        self.rucksacks
+            .chunks(3)
+            .map(|l| {
+                if l.len() < 3 {
+                    return Err(AocError::invalid(3, "incomplete group of elves"));
+                }
+                let fst = &l[0].chars().collect::<HashSet<char>>();
+                let snd = &l[1].chars().collect::<HashSet<char>>();
+                let thr = &l[2].chars().collect::<HashSet<char>>();
+                let chr = *fst
+                    .intersection(snd)
+                    .copied()
+                    .collect::<HashSet<char>>()
+                    .intersection(thr)
+                    .next()
+                    .ok_or_else(|| AocError::invalid(3, format!("no badge in group {:?}", l)))?
+                    as u32;
+                Ok(priority(chr))
+            })
+            .sum()
+    }
+}
+
+fn priority(chr: u32) -> u32 {
+    if 'a' as u32 <= chr && 'z' as u32 >= chr {
+        chr - ('a' as u32) + 1
+    } else {
+        chr - ('A' as u32) + 27
+    }
+}
+
+pub fn day3_part1(filename: &str) -> Result<u32, AocError> {
+    day3_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day3_part1_from_str(input: &str) -> Result<u32, AocError> {
+    Day3::parse(input)?.part1()
+}
+
+pub fn day3_part1_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day3::parse_reader(reader)?.part1()
+}
+
+pub fn day3_part2(filename: &str) -> Result<u32, AocError> {
+    day3_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day3_part2_from_str(input: &str) -> Result<u32, AocError> {
+    Day3::parse(input)?.part2()
+}
+
+pub fn day3_part2_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day3::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day3_test() {
+    assert_eq!(7850, day3_part1("inputs/3.txt").unwrap());
+    assert_eq!(2581, day3_part2("inputs/3.txt").unwrap());
+}
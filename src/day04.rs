@@ -0,0 +1,94 @@
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::fs::File;
+use std::io::Read;
+
+/// Each pair of section assignments, as `(start1, end1, start2, end2)`.
+pub struct Day4 {
+    pub pairs: Vec<(u32, u32, u32, u32)>,
+}
+
+impl Solution for Day4 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let re = Regex::new(r"^(?P<fst>\d+)-(?P<snd>\d+),(?P<thr>\d+)-(?P<fth>\d+)\s*$").unwrap();
+        let pairs = input
+            .lines()
+            .enumerate()
+            .map(|(i, l)| {
+                let caps = re
+                    .captures(l)
+                    .ok_or_else(|| AocError::parse(4, i + 1, l, "expected a-b,c-d"))?;
+                let num = |name: &str| {
+                    caps[name]
+                        .parse::<u32>()
+                        .map_err(|e| AocError::parse(4, i + 1, l, e.to_string()))
+                };
+                Ok((num("fst")?, num("snd")?, num("thr")?, num("fth")?))
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(Day4 { pairs })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self
+            .pairs
+            .iter()
+            .filter(|&&(fst, snd, thr, fth)| {
+                (fst >= thr && snd <= fth) || (thr >= fst && fth <= snd)
+            })
+            .count())
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self
+            .pairs
+            .iter()
+            .filter(|&&(fst, snd, thr, fth)| {
+                (fst >= thr && snd <= fth)
+                    || (thr >= fst && fth <= snd)
+                    || (fst >= thr && fst <= fth)
+                    || (snd >= thr && snd <= fth)
+            })
+            .count())
+    }
+}
+
+pub fn day4_part1(filename: &str) -> Result<usize, AocError> {
+    day4_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day4_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day4::parse(input)?.part1()
+}
+
+pub fn day4_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day4::parse_reader(reader)?.part1()
+}
+
+pub fn day4_part2(filename: &str) -> Result<usize, AocError> {
+    day4_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day4_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day4::parse(input)?.part2()
+}
+
+pub fn day4_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day4::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day4_test() {
+    assert_eq!(471, day4_part1("inputs/4.txt").unwrap());
+    assert_eq!(888, day4_part2("inputs/4.txt").unwrap());
+}
+
+#[test]
+fn day4_from_reader_test() {
+    let input = "2-4,6-8\n2-3,4-5\n5-7,7-9\n2-8,3-7\n6-6,4-6\n2-6,4-8\n";
+    assert_eq!(2, day4_part1_from_reader(input.as_bytes()).unwrap());
+    assert_eq!(4, day4_part2_from_reader(input.as_bytes()).unwrap());
+}
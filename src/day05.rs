@@ -0,0 +1,156 @@
+use crate::common::split_blocks;
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::fs::File;
+use std::io::Read;
+
+/// The starting stacks (bottom first), and moves as `(count, from, to)` with
+/// 1-based stack numbers.
+pub struct Day5 {
+    pub stacks: Vec<Vec<char>>,
+    pub moves: Vec<(u32, usize, usize)>,
+}
+
+impl Solution for Day5 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let re = Regex::new(r"^move (?P<count>\d+) from (?P<from>\d+) to (?P<to>\d+)\s*$").unwrap();
+        let v = split_blocks(input);
+        if v.len() != 2 {
+            return Err(AocError::invalid(
+                5,
+                "expected the stacks and the moves separated by a blank line",
+            ));
+        }
+        let (_, first_part) = v[0];
+        let (moves_start, second_part) = v[1];
+        let stacks_line = first_part.lines().count();
+        let last_line = first_part.lines().next_back().unwrap_or_default();
+        let stacks_num = last_line
+            .split(' ')
+            .next_back()
+            .unwrap_or_default()
+            .parse::<usize>()
+            .map_err(|_| AocError::parse(5, stacks_line, last_line, "expected stack numbers"))?;
+        let mut stacks: Vec<Vec<char>> = Vec::new();
+        for _ in 0..stacks_num {
+            stacks.push(Vec::new());
+        }
+        let first_lines = first_part.lines().collect::<Vec<_>>();
+        for (i, line) in first_lines.into_iter().enumerate().rev().skip(1) {
+            for (j, _) in line.match_indices('[') {
+                let chr = line
+                    .chars()
+                    .nth(j + 1)
+                    .ok_or_else(|| AocError::parse(5, i + 1, line, "unterminated crate"))?;
+                stacks
+                    .get_mut(j / 4)
+                    .ok_or_else(|| AocError::parse(5, i + 1, line, "crate outside any stack"))?
+                    .push(chr);
+            }
+        }
+
+        let moves = second_part
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let line_num = moves_start + i;
+                let caps = re.captures(line).ok_or_else(|| {
+                    AocError::parse(5, line_num, line, "expected move n from a to b")
+                })?;
+                let count = caps["count"]
+                    .parse::<u32>()
+                    .map_err(|e| AocError::parse(5, line_num, line, e.to_string()))?;
+                let stack = |name: &str| match caps[name].parse::<usize>() {
+                    Ok(n) if (1..=stacks_num).contains(&n) => Ok(n),
+                    _ => Err(AocError::parse(5, line_num, line, "no such stack")),
+                };
+                Ok((count, stack("from")?, stack("to")?))
+            })
+            .collect::<Result<_, AocError>>()?;
+        Ok(Day5 { stacks, moves })
+    }
+
+    fn part1(&self) -> Result<String, AocError> {
+        let mut stacks = self.stacks.clone();
+        for &(count, from, to) in self.moves.iter() {
+            for _ in 0..count {
+                let temp = stacks[from - 1].pop().ok_or_else(|| empty_stack(from))?;
+                stacks[to - 1].push(temp);
+            }
+        }
+
+        top_crates(&stacks)
+    }
+
+    fn part2(&self) -> Result<String, AocError> {
+        let mut stacks = self.stacks.clone();
+        for &(count, from, to) in self.moves.iter() {
+            let mut temp: Vec<char> = Vec::new();
+            for _ in 0..count {
+                temp.push(stacks[from - 1].pop().ok_or_else(|| empty_stack(from))?);
+            }
+            for chr in temp.iter().rev() {
+                stacks[to - 1].push(*chr);
+            }
+        }
+
+        top_crates(&stacks)
+    }
+}
+
+fn empty_stack(stack: usize) -> AocError {
+    AocError::invalid(5, format!("moved a crate from empty stack {}", stack))
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> Result<String, AocError> {
+    stacks
+        .iter()
+        .enumerate()
+        .map(|(i, stack)| stack.last().copied().ok_or_else(|| empty_stack(i + 1)))
+        .collect::<Result<String, _>>()
+}
+
+pub fn day5_part1(filename: &str) -> Result<String, AocError> {
+    day5_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day5_part1_from_str(input: &str) -> Result<String, AocError> {
+    Day5::parse(input)?.part1()
+}
+
+pub fn day5_part1_from_reader(reader: impl Read) -> Result<String, AocError> {
+    Day5::parse_reader(reader)?.part1()
+}
+
+pub fn day5_part2(filename: &str) -> Result<String, AocError> {
+    day5_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day5_part2_from_str(input: &str) -> Result<String, AocError> {
+    Day5::parse(input)?.part2()
+}
+
+pub fn day5_part2_from_reader(reader: impl Read) -> Result<String, AocError> {
+    Day5::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day5_test() {
+    assert_eq!("HNSNMTLHQ", day5_part1("inputs/5.txt").unwrap());
+    assert_eq!("RNLFDJMCT", day5_part2("inputs/5.txt").unwrap());
+}
+
+#[test]
+fn day5_parse_error_test() {
+    let input = "    [D]\n[N] [C]\n 1   2\n\nmove 1 from 2 to 1\nmove 1 from 3 to 1\n";
+    match Day5::parse(input) {
+        Err(AocError::Parse { line, reason, .. }) => {
+            assert_eq!(6, line);
+            assert_eq!("no such stack", reason);
+        }
+        _ => panic!("expected a parse error"),
+    }
+}
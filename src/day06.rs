@@ -0,0 +1,103 @@
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day6 {
+    pub signal: Vec<char>,
+}
+
+impl Solution for Day6 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Day6 {
+            signal: input.trim_end().chars().collect(),
+        })
+    }
+
+    fn part1(&self) -> Result<u32, AocError> {
+        find_marker(&self.signal, 4)
+    }
+
+    fn part2(&self) -> Result<u32, AocError> {
+        find_marker(&self.signal, 14)
+    }
+}
+
+fn find_marker(input: &[char], marker_len: usize) -> Result<u32, AocError> {
+    let no_marker = || AocError::invalid(6, format!("no marker of length {}", marker_len));
+    if input.len() < marker_len {
+        return Err(no_marker());
+    }
+    let mut buffer: VecDeque<char> = VecDeque::new();
+    for i in &input[0..marker_len] {
+        buffer.push_back(*i);
+    }
+    let mut input_to_process = input
+        .iter()
+        .skip(marker_len)
+        .rev()
+        .copied()
+        .collect::<Vec<char>>();
+    let mut i = marker_len as u32;
+
+    while buffer.iter().collect::<HashSet<&char>>().len() < marker_len {
+        buffer.pop_front();
+        buffer.push_back(input_to_process.pop().ok_or_else(no_marker)?);
+        i += 1;
+    }
+    Ok(i)
+}
+
+pub fn day6_part1(filename: &str) -> Result<u32, AocError> {
+    day6_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day6_part1_from_str(input: &str) -> Result<u32, AocError> {
+    Day6::parse(input)?.part1()
+}
+
+pub fn day6_part1_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day6::parse_reader(reader)?.part1()
+}
+
+pub fn day6_part2(filename: &str) -> Result<u32, AocError> {
+    day6_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day6_part2_from_str(input: &str) -> Result<u32, AocError> {
+    Day6::parse(input)?.part2()
+}
+
+pub fn day6_part2_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day6::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day6_test() {
+    assert_eq!(1929, day6_part1("inputs/6.txt").unwrap());
+    assert_eq!(3298, day6_part2("inputs/6.txt").unwrap());
+}
+
+#[test]
+fn day6_from_str_test() {
+    assert_eq!(
+        7,
+        day6_part1_from_str("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap()
+    );
+    assert_eq!(
+        19,
+        day6_part2_from_str("mjqjpqmgbljsphdztnvjfqwrcgsmlb").unwrap()
+    );
+    assert_eq!(
+        11,
+        day6_part1_from_str("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()
+    );
+    assert_eq!(
+        26,
+        day6_part2_from_str("zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw").unwrap()
+    );
+}
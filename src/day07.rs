@@ -0,0 +1,138 @@
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day7 {
+    pub tree: TreeNode,
+}
+
+impl Solution for Day7 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let mut lines = input.lines().enumerate();
+        match lines.next() {
+            Some((_, "$ cd /")) => {}
+            Some((_, line)) => return Err(AocError::parse(7, 1, line, "expected $ cd /")),
+            None => return Err(AocError::invalid(7, "empty input")),
+        }
+        let mut input = lines.map(|(i, l)| (i + 1, l)).collect::<Vec<_>>();
+        Ok(Day7 {
+            tree: create_tree("/", &mut input)?,
+        })
+    }
+
+    fn part1(&self) -> Result<u64, AocError> {
+        Ok(get_dir_sizes(&self.tree)
+            .iter()
+            .filter(|&s| *s <= 100000)
+            .sum())
+    }
+
+    fn part2(&self) -> Result<u64, AocError> {
+        let total_diskspace: u64 = 70000000;
+        let total_used_space = get_node_size(&self.tree);
+        let current_unused_space = total_diskspace.saturating_sub(total_used_space);
+        let space_required_to_free = 30000000_u64.saturating_sub(current_unused_space);
+        get_dir_sizes(&self.tree)
+            .iter()
+            .filter(|&s| *s >= space_required_to_free)
+            .min()
+            .copied()
+            .ok_or_else(|| AocError::invalid(7, "no directory is big enough to free the space"))
+    }
+}
+
+pub fn day7_part1(filename: &str) -> Result<u64, AocError> {
+    day7_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day7_part1_from_str(input: &str) -> Result<u64, AocError> {
+    Day7::parse(input)?.part1()
+}
+
+pub fn day7_part1_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day7::parse_reader(reader)?.part1()
+}
+
+pub fn day7_part2(filename: &str) -> Result<u64, AocError> {
+    day7_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day7_part2_from_str(input: &str) -> Result<u64, AocError> {
+    Day7::parse(input)?.part2()
+}
+
+pub fn day7_part2_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day7::parse_reader(reader)?.part2()
+}
+
+/// A filesystem entry reconstructed from the terminal output.
+#[derive(Debug)]
+pub enum TreeNode {
+    File(String, u64),
+    Directory(String, Vec<TreeNode>),
+}
+
+fn create_tree(dirname: &str, inp_lines: &mut Vec<(usize, &str)>) -> Result<TreeNode, AocError> {
+    let mut v_tree: Vec<TreeNode> = Vec::new();
+    while !inp_lines.is_empty() {
+        let (line_num, line) = inp_lines.remove(0);
+        if line == "$ ls" {
+            while !inp_lines.is_empty() && !inp_lines[0].1.starts_with('$') {
+                let (sub_line_num, sub_line) = inp_lines.remove(0);
+                if sub_line.starts_with("dir ") {
+                    continue;
+                }
+                let (size, filename) = sub_line.split_once(' ').ok_or_else(|| {
+                    AocError::parse(7, sub_line_num, sub_line, "expected <size> <name>")
+                })?;
+                let size = size
+                    .parse::<u64>()
+                    .map_err(|e| AocError::parse(7, sub_line_num, sub_line, e.to_string()))?;
+                v_tree.push(TreeNode::File(filename.to_string(), size));
+            }
+        } else if line == "$ cd .." {
+            break;
+        } else if let Some(new_dirname) = line.strip_prefix("$ cd ") {
+            v_tree.push(create_tree(new_dirname, inp_lines)?);
+        } else {
+            return Err(AocError::parse(7, line_num, line, "unknown command"));
+        }
+    }
+    Ok(TreeNode::Directory(String::from(dirname), v_tree))
+}
+
+/// The sizes of every directory below `node`, not including `node` itself.
+pub fn get_dir_sizes(node: &TreeNode) -> Vec<u64> {
+    match node {
+        TreeNode::File(_, _) => vec![],
+        TreeNode::Directory(_, elems) => {
+            let mut ret = Vec::new();
+            for elem in elems.iter() {
+                match elem {
+                    TreeNode::Directory(_, _) => {
+                        ret.push(get_node_size(elem));
+                        ret.extend(get_dir_sizes(elem));
+                    }
+                    TreeNode::File(_, _) => {}
+                }
+            }
+            ret
+        }
+    }
+}
+
+pub fn get_node_size(node: &TreeNode) -> u64 {
+    match node {
+        TreeNode::File(_, filesize) => *filesize,
+        TreeNode::Directory(_, elems) => elems.iter().map(get_node_size).sum(),
+    }
+}
+
+#[test]
+fn day7_test() {
+    assert_eq!(1141028, day7_part1("inputs/7.txt").unwrap());
+    assert_eq!(8278005, day7_part2("inputs/7.txt").unwrap());
+}
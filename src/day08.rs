@@ -0,0 +1,101 @@
+use crate::geometry::Point;
+use crate::grid::{Grid, ORTHOGONAL};
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+
+/// Tree heights.
+pub struct Day8 {
+    pub grid: Grid<u32>,
+}
+
+impl Solution for Day8 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        Ok(Day8 {
+            grid: Grid::parse(input, 8, |c| c.to_digit(10))?,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self
+            .grid
+            .positions()
+            .filter(|&pos| tree_is_visible(&self.grid, pos))
+            .count())
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self
+            .grid
+            .positions()
+            .map(|pos| scenic_score(&self.grid, pos))
+            .max()
+            .unwrap_or(0))
+    }
+}
+
+pub fn day8_part1(filename: &str) -> Result<usize, AocError> {
+    day8_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day8_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day8::parse(input)?.part1()
+}
+
+pub fn day8_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day8::parse_reader(reader)?.part1()
+}
+
+pub fn day8_part2(filename: &str) -> Result<usize, AocError> {
+    day8_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day8_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day8::parse(input)?.part2()
+}
+
+pub fn day8_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day8::parse_reader(reader)?.part2()
+}
+
+/// A tree is visible if every tree between it and an edge is shorter.
+fn tree_is_visible(grid: &Grid<u32>, pos: Point<usize>) -> bool {
+    let main_tree_height = grid[pos];
+    ORTHOGONAL.into_iter().any(|step| {
+        grid.ray(pos, step)
+            .all(|(_, &tree_height)| tree_height < main_tree_height)
+    })
+}
+
+fn scenic_score(grid: &Grid<u32>, pos: Point<usize>) -> usize {
+    let main_tree_height = grid[pos];
+    ORTHOGONAL
+        .into_iter()
+        .map(|step| {
+            let mut viewing_distance = 0;
+            for (_, &tree_height) in grid.ray(pos, step) {
+                viewing_distance += 1;
+                if tree_height >= main_tree_height {
+                    break;
+                }
+            }
+            viewing_distance
+        })
+        .product()
+}
+
+#[test]
+fn day8_example_test() {
+    let input = "30373\n25512\n65332\n33549\n35390\n";
+    assert_eq!(21, day8_part1_from_str(input).unwrap());
+    assert_eq!(8, day8_part2_from_str(input).unwrap());
+}
+
+#[test]
+fn day8_test() {
+    assert_eq!(1700, day8_part1("inputs/8.txt").unwrap());
+    assert_eq!(470596, day8_part2("inputs/8.txt").unwrap());
+}
@@ -0,0 +1,104 @@
+use crate::geometry::{Direction, Point};
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day9 {
+    pub motions: Vec<(Direction, u32)>,
+}
+
+impl Solution for Day9 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let motions = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let (direction, count) = line.split_once(' ').ok_or_else(|| {
+                    AocError::parse(9, i + 1, line, "expected <direction> <count>")
+                })?;
+                let direction = direction
+                    .parse()
+                    .map_err(|e| AocError::parse(9, i + 1, line, format!("{}", e)))?;
+                let count = count.parse().map_err(|e| {
+                    AocError::parse(9, i + 1, line, format!("invalid count: {}", e))
+                })?;
+                Ok((direction, count))
+            })
+            .collect::<Result<Vec<(Direction, u32)>, AocError>>()?;
+        Ok(Day9 { motions })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        let mut head_pos = Point::new(0, 0);
+        let mut tail_pos = Point::new(0, 0);
+        let mut tail_positions = HashSet::from([tail_pos]);
+        for &(direction, count) in self.motions.iter() {
+            for _ in 0..count {
+                head_pos += direction.delta();
+                tail_pos = move_tail(head_pos, tail_pos);
+                tail_positions.insert(tail_pos);
+            }
+        }
+        Ok(tail_positions.len())
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        let mut positions = [Point::new(0, 0); 10];
+        let mut tail_positions = HashSet::from([*positions.last().unwrap()]);
+        for &(direction, count) in self.motions.iter() {
+            for _ in 0..count {
+                positions[0] += direction.delta();
+                for i in 1..positions.len() {
+                    positions[i] = move_tail(positions[i - 1], positions[i]);
+                }
+                tail_positions.insert(*positions.last().unwrap());
+            }
+        }
+        Ok(tail_positions.len())
+    }
+}
+
+pub fn day9_part1(filename: &str) -> Result<usize, AocError> {
+    day9_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day9_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day9::parse(input)?.part1()
+}
+
+pub fn day9_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day9::parse_reader(reader)?.part1()
+}
+
+pub fn day9_part2(filename: &str) -> Result<usize, AocError> {
+    day9_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day9_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day9::parse(input)?.part2()
+}
+
+pub fn day9_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day9::parse_reader(reader)?.part2()
+}
+
+/// Where the tail ends up after the knot ahead of it has moved. The tail
+/// stays put while the two are touching, and otherwise takes one step
+/// (diagonally if need be) towards the head.
+fn move_tail(head_pos: Point<i32>, tail_pos: Point<i32>) -> Point<i32> {
+    if head_pos.chebyshev(tail_pos) <= 1 {
+        tail_pos
+    } else {
+        tail_pos + (head_pos - tail_pos).signum()
+    }
+}
+
+#[test]
+fn day9_test() {
+    assert_eq!(5710, day9_part1("inputs/9.txt").unwrap());
+    assert_eq!(2259, day9_part2("inputs/9.txt").unwrap());
+}
@@ -0,0 +1,172 @@
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day10 {
+    pub program: Vec<Instruction>,
+}
+
+impl Solution for Day10 {
+    type Part1 = i32;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let program = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                parse_instruction(line)
+                    .ok_or_else(|| AocError::parse(10, i + 1, line, "unknown instruction"))
+            })
+            .collect::<Result<Vec<_>, _>>()?;
+        if program.is_empty() {
+            return Err(AocError::invalid(10, "empty program"));
+        }
+        Ok(Day10 { program })
+    }
+
+    fn part1(&self) -> Result<i32, AocError> {
+        let mut program = self.program.iter().rev().cloned().collect::<Vec<_>>();
+        let mut current_cycle = 1;
+        let mut current_instruction = program.pop().unwrap();
+        let mut add_counter = 0;
+        let mut x_register = 1;
+        let mut res = 0;
+        while current_cycle <= 220 {
+            if HashSet::from([20, 60, 100, 140, 180, 220]).contains(&current_cycle) {
+                res += current_cycle * x_register;
+            }
+            match current_instruction {
+                Instruction::Noop => match program.pop() {
+                    Some(instruction) => {
+                        current_instruction = instruction;
+                    }
+                    None => {
+                        break;
+                    }
+                },
+                Instruction::Add(to_add) if add_counter == 1 => {
+                    x_register += to_add;
+                    add_counter -= 1;
+                    match program.pop() {
+                        Some(instruction) => {
+                            current_instruction = instruction;
+                        }
+                        None => {
+                            break;
+                        }
+                    }
+                }
+                Instruction::Add(_) => {
+                    add_counter = 1;
+                }
+            }
+            current_cycle += 1;
+        }
+        Ok(res)
+    }
+
+    fn part2(&self) -> Result<String, AocError> {
+        let mut program = self.program.iter().rev().cloned().collect::<Vec<_>>();
+        let mut current_cycle = 1;
+        let mut current_instruction = program.pop().unwrap();
+        let mut add_counter = 0;
+        let mut x_register = 1;
+        let mut res = String::with_capacity(240);
+        while current_cycle <= 240 {
+            let cycle_horiz_position = (current_cycle % 40) - 1;
+            if cycle_horiz_position == x_register - 1
+                || cycle_horiz_position == x_register
+                || cycle_horiz_position == x_register + 1
+            {
+                res.push('#');
+            } else {
+                res.push('.');
+            }
+            if current_cycle % 40 == 0 {
+                res.push('\n');
+            }
+            match current_instruction {
+                Instruction::Noop => match program.pop() {
+                    Some(instruction) => {
+                        current_instruction = instruction;
+                    }
+                    None => {
+                        break;
+                    }
+                },
+                Instruction::Add(to_add) if add_counter == 1 => {
+                    x_register += to_add;
+                    add_counter -= 1;
+                    match program.pop() {
+                        Some(instruction) => {
+                            current_instruction = instruction;
+                        }
+                        None => {
+                            break;
+                        }
+                    }
+                }
+                Instruction::Add(_) => {
+                    add_counter = 1;
+                }
+            }
+            current_cycle += 1;
+        }
+        Ok(res)
+    }
+}
+
+pub fn day10_part1(filename: &str) -> Result<i32, AocError> {
+    day10_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day10_part1_from_str(input: &str) -> Result<i32, AocError> {
+    Day10::parse(input)?.part1()
+}
+
+pub fn day10_part1_from_reader(reader: impl Read) -> Result<i32, AocError> {
+    Day10::parse_reader(reader)?.part1()
+}
+
+pub fn day10_part2(filename: &str) -> Result<String, AocError> {
+    day10_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day10_part2_from_str(input: &str) -> Result<String, AocError> {
+    Day10::parse(input)?.part2()
+}
+
+pub fn day10_part2_from_reader(reader: impl Read) -> Result<String, AocError> {
+    Day10::parse_reader(reader)?.part2()
+}
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    Noop,
+    Add(i32),
+}
+
+fn parse_instruction(line: &str) -> Option<Instruction> {
+    match line.split_ascii_whitespace().collect::<Vec<&str>>()[..] {
+        ["noop"] => Some(Instruction::Noop),
+        ["addx", n] => n.parse().ok().map(Instruction::Add),
+        _ => None,
+    }
+}
+
+#[test]
+fn day10_test() {
+    assert_eq!(14860, day10_part1("inputs/10.txt").unwrap());
+    assert_eq!(
+        "\
+        ###...##..####.####.#..#.#..#.###..#..##\n\
+        #..#.#..#....#.#....#..#.#..#.#..#.#.#.#\n\
+        #..#.#......#..###..####.#..#.#..#.##...\n\
+        ###..#.##..#...#....#..#.#..#.###..#.#.#\n\
+        #.#..#..#.#....#....#..#.#..#.#.#..#.#.#\n\
+        #..#..###.####.####.#..#..##..#..#.#..#.\n",
+        day10_part2("inputs/10.txt").unwrap()
+    );
+}
@@ -0,0 +1,188 @@
+use crate::common::split_blocks;
+use crate::{AocError, Solution};
+use std::collections::VecDeque;
+use std::fs::File;
+use std::io::Read;
+use std::str::FromStr;
+
+pub struct Day11 {
+    pub notes: Vec<Note>,
+}
+
+impl Solution for Day11 {
+    type Part1 = u32;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let notes = split_blocks(input)
+            .into_iter()
+            .map(|(start, note)| parse_note(start, note))
+            .collect::<Result<Vec<_>, _>>()?;
+        for (i, note) in notes.iter().enumerate() {
+            if let Some(pass) = [note.true_pass, note.false_pass]
+                .into_iter()
+                .find(|&pass| pass >= notes.len() || pass == i)
+            {
+                return Err(AocError::invalid(
+                    11,
+                    format!("monkey {} can't throw to monkey {}", i, pass),
+                ));
+            }
+        }
+        Ok(Day11 { notes })
+    }
+
+    fn part1(&self) -> Result<u32, AocError> {
+        let mut notes = self.notes.clone();
+        let mut inspections = vec![0; notes.len()];
+        for _ in 0..20 {
+            for i in 0..notes.len() {
+                while !notes[i].items.is_empty() {
+                    let note = &mut notes[i];
+                    let mut item = note.items.pop_front().unwrap();
+                    item = note.operation.apply(item);
+                    item /= 3;
+                    let divisible_by = note.divisible_by;
+                    let true_pass = note.true_pass;
+                    let false_pass = note.false_pass;
+                    if item.is_multiple_of(divisible_by) {
+                        notes[true_pass].items.push_back(item);
+                    } else {
+                        notes[false_pass].items.push_back(item);
+                    }
+                    inspections[i] += 1;
+                }
+            }
+        }
+        inspections.sort();
+        Ok(inspections.iter().rev().take(2).product())
+    }
+
+    fn part2(&self) -> Result<u64, AocError> {
+        let mut notes = self.notes.clone();
+        let divisor: u64 = notes.iter().map(|n| n.divisible_by).product();
+        let mut inspections = vec![0; notes.len()];
+        for _ in 0..10000 {
+            for i in 0..notes.len() {
+                while !notes[i].items.is_empty() {
+                    let note = &mut notes[i];
+                    let mut item = note.items.pop_front().unwrap();
+                    item = note.operation.apply(item);
+                    item %= divisor;
+                    let divisible_by = note.divisible_by;
+                    let true_pass = note.true_pass;
+                    let false_pass = note.false_pass;
+                    if item.is_multiple_of(divisible_by) {
+                        notes[true_pass].items.push_back(item);
+                    } else {
+                        notes[false_pass].items.push_back(item);
+                    }
+                    inspections[i] += 1;
+                }
+            }
+        }
+        inspections.sort();
+        Ok(inspections.iter().rev().take(2).product())
+    }
+}
+
+pub fn day11_part1(filename: &str) -> Result<u32, AocError> {
+    day11_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day11_part1_from_str(input: &str) -> Result<u32, AocError> {
+    Day11::parse(input)?.part1()
+}
+
+pub fn day11_part1_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day11::parse_reader(reader)?.part1()
+}
+
+pub fn day11_part2(filename: &str) -> Result<u64, AocError> {
+    day11_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day11_part2_from_str(input: &str) -> Result<u64, AocError> {
+    Day11::parse(input)?.part2()
+}
+
+pub fn day11_part2_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day11::parse_reader(reader)?.part2()
+}
+
+/// A monkey's notes: the items it holds, and how it inspects and throws them.
+#[derive(Clone)]
+pub struct Note {
+    pub items: VecDeque<u64>,
+    pub operation: Operation,
+    pub divisible_by: u64,
+    pub true_pass: usize,
+    pub false_pass: usize,
+}
+
+#[derive(Clone, Copy)]
+pub enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+impl Operation {
+    pub fn apply(&self, n: u64) -> u64 {
+        match self {
+            Operation::Add(num) => n + num,
+            Operation::Multiply(num) => n * num,
+            Operation::Square => n * n,
+        }
+    }
+}
+
+fn parse_note(start: usize, note: &str) -> Result<Note, AocError> {
+    let note_vec = note.lines().collect::<Vec<_>>();
+    if note_vec.len() != 6 {
+        return Err(AocError::parse(
+            11,
+            start,
+            note_vec.first().unwrap_or(&""),
+            "expected 6 lines describing a monkey",
+        ));
+    }
+    let err = |i: usize, reason: &str| AocError::parse(11, start + i, note_vec[i], reason);
+    Ok(Note {
+        items: note_vec[1]
+            .strip_prefix("  Starting items: ")
+            .ok_or_else(|| err(1, "expected starting items"))?
+            .split(", ")
+            .map(|n| n.parse().map_err(|_| err(1, "invalid item")))
+            .collect::<Result<_, _>>()?,
+        operation: {
+            let parts = note_vec[2].split(' ').rev().take(2).collect::<Vec<_>>();
+            if parts == ["old", "*"] {
+                Operation::Square
+            } else {
+                let num: u64 = parts[0].parse().map_err(|_| err(2, "invalid operand"))?;
+                match parts.get(1) {
+                    Some(&"+") => Operation::Add(num),
+                    Some(&"*") => Operation::Multiply(num),
+                    _ => return Err(err(2, "operator must be + or *")),
+                }
+            }
+        },
+        divisible_by: match last_number(note_vec[3]).ok_or_else(|| err(3, "invalid divisor"))? {
+            0 => return Err(err(3, "divisor must not be 0")),
+            n => n,
+        },
+        true_pass: last_number(note_vec[4]).ok_or_else(|| err(4, "invalid monkey number"))?,
+        false_pass: last_number(note_vec[5]).ok_or_else(|| err(5, "invalid monkey number"))?,
+    })
+}
+
+fn last_number<T: FromStr>(line: &str) -> Option<T> {
+    line.split(' ').next_back().and_then(|n| n.parse().ok())
+}
+
+#[test]
+fn day11_test() {
+    assert_eq!(67830, day11_part1("inputs/11.txt").unwrap());
+    assert_eq!(15305381442, day11_part2("inputs/11.txt").unwrap());
+}
@@ -0,0 +1,120 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+
+/// The heightmap, including the `S` and `E` markers.
+pub struct Day12 {
+    pub heightmap: Grid<char>,
+}
+
+impl Solution for Day12 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let heightmap = Grid::parse(input, 12, |c| {
+            if c.is_ascii_lowercase() || c == 'S' || c == 'E' {
+                Some(c)
+            } else {
+                None
+            }
+        })?;
+        for marker in ['S', 'E'] {
+            if heightmap.iter().filter(|&(_, &c)| c == marker).count() != 1 {
+                return Err(AocError::invalid(
+                    12,
+                    format!("expected exactly one {} in the heightmap", marker),
+                ));
+            }
+        }
+        Ok(Day12 { heightmap })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        let start = self.heightmap.find(|&c| c == 'S').unwrap();
+        self.shortest_climb([start])
+            .ok_or_else(|| AocError::invalid(12, "no path to E"))
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        let starts = self
+            .heightmap
+            .iter()
+            .filter(|&(_, &c)| c == 'S' || c == 'a')
+            .map(|(pos, _)| pos);
+        self.shortest_climb(starts)
+            .ok_or_else(|| AocError::invalid(12, "no path from elevation a to E"))
+    }
+}
+
+impl Day12 {
+    /// The fewest steps from any of `starts` to `E`.
+    fn shortest_climb(&self, starts: impl IntoIterator<Item = Point<usize>>) -> Option<usize> {
+        let heightmap = &self.heightmap;
+        bfs(
+            starts,
+            |&pos| neighbours_of(pos, heightmap),
+            |&pos| heightmap[pos] == 'E',
+        )
+        .map(|path| path.cost)
+    }
+}
+
+pub fn day12_part1(filename: &str) -> Result<usize, AocError> {
+    day12_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day12_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day12::parse(input)?.part1()
+}
+
+pub fn day12_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day12::parse_reader(reader)?.part1()
+}
+
+/// The neighbours that can be stepped to from `start` when climbing.
+fn neighbours_of(
+    start: Point<usize>,
+    heightmap: &Grid<char>,
+) -> impl Iterator<Item = Point<usize>> + '_ {
+    let start_elevation = get_elevation(start, heightmap);
+    heightmap
+        .neighbours4(start)
+        .filter(move |&pos| (start_elevation + 1) >= get_elevation(pos, heightmap))
+}
+
+fn get_elevation(pos: Point<usize>, heightmap: &Grid<char>) -> u32 {
+    match heightmap[pos] {
+        'S' => 0,
+        'E' => ('z' as u32) - ('a' as u32),
+        c => (c as u32) - ('a' as u32),
+    }
+}
+
+pub fn day12_part2(filename: &str) -> Result<usize, AocError> {
+    day12_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day12_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day12::parse(input)?.part2()
+}
+
+pub fn day12_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day12::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day12_example_test() {
+    let input = "Sabqponm\nabcryxxl\naccszExk\nacctuvwj\nabdefghi\n";
+    assert_eq!(31, day12_part1_from_str(input).unwrap());
+    assert_eq!(29, day12_part2_from_str(input).unwrap());
+}
+
+#[test]
+fn day12_test() {
+    assert_eq!(497, day12_part1("inputs/12.txt").unwrap());
+    assert_eq!(492, day12_part2("inputs/12.txt").unwrap());
+}
@@ -0,0 +1,137 @@
+use crate::common::split_blocks;
+use crate::{AocError, Solution};
+use serde::Deserialize;
+use std::cmp::Ordering;
+use std::fs::File;
+use std::io::Read;
+
+pub struct Day13 {
+    pub pairs: Vec<(Packet, Packet)>,
+}
+
+impl Solution for Day13 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let pairs = split_blocks(input)
+            .into_iter()
+            .map(|(start, pair)| {
+                let mut parsed_pair = pair
+                    .lines()
+                    .enumerate()
+                    .map(|(i, l)| {
+                        serde_json::from_str::<Packet>(l)
+                            .map_err(|e| AocError::parse(13, start + i, l, e.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                if parsed_pair.len() != 2 {
+                    return Err(AocError::parse(
+                        13,
+                        start,
+                        pair,
+                        "expected a pair of packets",
+                    ));
+                }
+                Ok((parsed_pair.remove(0), parsed_pair.remove(0)))
+            })
+            .collect::<Result<_, _>>()?;
+        Ok(Day13 { pairs })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        let mut res = 0;
+        for (i, pair) in self.pairs.iter().enumerate() {
+            if pair_in_right_order(&pair.0, &pair.1) == Ordering::Less {
+                res += i + 1;
+            }
+        }
+        Ok(res)
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        let mut pairs = self
+            .pairs
+            .iter()
+            .flat_map(|(left, right)| [left.clone(), right.clone()])
+            .collect::<Vec<_>>();
+        let first_divider = Packet::List(vec![Packet::List(vec![Packet::Num(2)])]);
+        let second_divider = Packet::List(vec![Packet::List(vec![Packet::Num(6)])]);
+        pairs.push(first_divider.clone());
+        pairs.push(second_divider.clone());
+
+        pairs.sort_by(pair_in_right_order);
+
+        let first_pos = pairs
+            .iter()
+            .position(|pair| pair == &first_divider)
+            .unwrap()
+            + 1;
+        let second_pos = pairs
+            .iter()
+            .position(|pair| pair == &second_divider)
+            .unwrap()
+            + 1;
+        Ok(first_pos * second_pos)
+    }
+}
+
+pub fn day13_part1(filename: &str) -> Result<usize, AocError> {
+    day13_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day13_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day13::parse(input)?.part1()
+}
+
+pub fn day13_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day13::parse_reader(reader)?.part1()
+}
+
+pub fn day13_part2(filename: &str) -> Result<usize, AocError> {
+    day13_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day13_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day13::parse(input)?.part2()
+}
+
+pub fn day13_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day13::parse_reader(reader)?.part2()
+}
+
+#[derive(Clone, Debug, Deserialize, serde::Serialize)]
+#[serde(untagged)]
+#[derive(PartialEq)]
+pub enum Packet {
+    Num(u32),
+    List(Vec<Packet>),
+}
+
+/// Compares two packets; `Ordering::Less` means they are in the right order.
+pub fn pair_in_right_order(left: &Packet, right: &Packet) -> Ordering {
+    match (left, right) {
+        (Packet::Num(l), Packet::Num(r)) => l.cmp(r),
+        (Packet::Num(_), Packet::List(_)) => {
+            pair_in_right_order(&Packet::List(vec![left.clone()]), right)
+        }
+        (Packet::List(_), Packet::Num(_)) => {
+            pair_in_right_order(left, &Packet::List(vec![right.clone()]))
+        }
+        (Packet::List(l), Packet::List(r)) => {
+            for (ll, rr) in l.iter().zip(r) {
+                match pair_in_right_order(ll, rr) {
+                    Ordering::Equal => continue,
+                    other => return other,
+                }
+            }
+            l.len().cmp(&r.len())
+        }
+    }
+}
+
+#[test]
+fn day13_test() {
+    assert_eq!(4894, day13_part1("inputs/13.txt").unwrap());
+    assert_eq!(24180, day13_part2("inputs/13.txt").unwrap());
+}
@@ -0,0 +1,191 @@
+use crate::geometry::Point;
+use crate::grid::Grid;
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Tile::Air => write!(f, "."),
+            Tile::Rock => write!(f, "#"),
+            Tile::Sand => write!(f, "o"),
+        }
+    }
+}
+
+/// A slice of the cave, from `y = 0` down to just above the floor, and wide
+/// enough for sand to pile up on the floor without reaching the sides.
+pub struct Day14 {
+    pub cave: Grid<Tile>,
+    /// The x coordinate of the leftmost column of `cave`.
+    pub min_x: usize,
+    pub lowest_rock: usize,
+}
+
+impl Solution for Day14 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let points = parse_rocks(input)?;
+        if points.is_empty() {
+            return Err(AocError::invalid(14, "no rocks in input"));
+        }
+        let lowest_rock = points.iter().map(|p| p.y).max().unwrap();
+        let floor_height = lowest_rock + 2;
+        // Sand piles up in a triangle on the floor, so it can't spread further
+        // than floor_height from the spawn point in either direction
+        let min_x = points
+            .iter()
+            .map(|p| p.x)
+            .chain([SAND_SPAWN_X.saturating_sub(floor_height)])
+            .min()
+            .unwrap()
+            .saturating_sub(1);
+        let max_x = points
+            .iter()
+            .map(|p| p.x)
+            .chain([SAND_SPAWN_X + floor_height])
+            .max()
+            .unwrap()
+            + 1;
+        let mut cave = Grid::new(max_x - min_x + 1, floor_height, Tile::Air);
+        for p in points {
+            cave[Point::new(p.x - min_x, p.y)] = Tile::Rock;
+        }
+        Ok(Day14 {
+            cave,
+            min_x,
+            lowest_rock,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.pour_sand(true).1)
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        Ok(self.pour_sand(false).1)
+    }
+}
+
+const SAND_SPAWN_X: usize = 500;
+
+impl Day14 {
+    /// Pours sand into the cave until it either falls into the abyss below the
+    /// lowest rock (if `abyss` is set) or blocks the spawn point. Returns the
+    /// final state of the cave and how much sand came to rest.
+    pub fn pour_sand(&self, abyss: bool) -> (Grid<Tile>, usize) {
+        let mut cave = self.cave.clone();
+        let sand_spawn_point = Point::new(SAND_SPAWN_X - self.min_x, 0);
+        let mut caught_sand = 0;
+        while cave[sand_spawn_point] == Tile::Air {
+            let mut sand_grain = sand_spawn_point;
+            // The bottom row of the cave is just above the floor, so a grain
+            // there can't move any further down
+            while let Some(next) = [Point::new(0, 1), Point::new(-1, 1), Point::new(1, 1)]
+                .into_iter()
+                .filter_map(|step| cave.offset(sand_grain, step))
+                .find(|&pos| cave[pos] == Tile::Air)
+            {
+                sand_grain = next;
+            }
+            if abyss && sand_grain.y > self.lowest_rock {
+                break;
+            }
+            cave[sand_grain] = Tile::Sand;
+            caught_sand += 1;
+        }
+        (cave, caught_sand)
+    }
+}
+
+pub fn day14_part1(filename: &str) -> Result<usize, AocError> {
+    day14_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day14_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day14::parse(input)?.part1()
+}
+
+pub fn day14_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day14::parse_reader(reader)?.part1()
+}
+
+pub fn day14_part2(filename: &str) -> Result<usize, AocError> {
+    day14_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day14_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day14::parse(input)?.part2()
+}
+
+pub fn day14_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day14::parse_reader(reader)?.part2()
+}
+
+fn parse_rocks(input: &str) -> Result<HashSet<Point<usize>>, AocError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(i, line)| {
+            let points = line
+                .split(" -> ")
+                .map(|point| {
+                    let (x, y) = point
+                        .split_once(',')
+                        .ok_or_else(|| AocError::parse(14, i + 1, line, "expected x,y"))?;
+                    let coord = |c: &str| {
+                        c.parse::<usize>()
+                            .map_err(|e| AocError::parse(14, i + 1, line, e.to_string()))
+                    };
+                    Ok(Point::new(coord(x)?, coord(y)?))
+                })
+                .collect::<Result<Vec<Point<usize>>, AocError>>()?;
+            points
+                .windows(2)
+                .map(|segment| {
+                    let (first, second) = (segment[0], segment[1]);
+                    if first.x == second.x {
+                        let (top, bottom) = (first.y.min(second.y), first.y.max(second.y));
+                        Ok((top..=bottom).map(|y| Point::new(first.x, y)).collect())
+                    } else if first.y == second.y {
+                        let (left, right) = (first.x.min(second.x), first.x.max(second.x));
+                        Ok((left..=right).map(|x| Point::new(x, first.y)).collect())
+                    } else {
+                        Err(AocError::parse(
+                            14,
+                            i + 1,
+                            line,
+                            "rock paths must be straight",
+                        ))
+                    }
+                })
+                .collect::<Result<Vec<HashSet<_>>, _>>()
+        })
+        .collect::<Result<Vec<_>, _>>()
+        .map(|paths| paths.into_iter().flatten().flatten().collect())
+}
+
+#[test]
+fn day14_example_test() {
+    let input = "498,4 -> 498,6 -> 496,6\n503,4 -> 502,4 -> 502,9 -> 494,9\n";
+    assert_eq!(24, day14_part1_from_str(input).unwrap());
+    assert_eq!(93, day14_part2_from_str(input).unwrap());
+}
+
+#[test]
+fn day14_test() {
+    assert_eq!(979, day14_part1("inputs/14.txt").unwrap());
+    assert_eq!(29044, day14_part2("inputs/14.txt").unwrap());
+}
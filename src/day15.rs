@@ -0,0 +1,228 @@
+use crate::geometry::Point;
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Sensor {
+    pub position: Point<i64>,
+    pub beacon: Point<i64>,
+}
+
+impl Sensor {
+    /// How far the sensor can see: no other beacon is within this distance.
+    pub fn radius(&self) -> i64 {
+        self.position.manhattan(self.beacon)
+    }
+
+    /// The range of x positions the sensor covers on row `y`, inclusive.
+    pub fn coverage(&self, y: i64) -> Option<(i64, i64)> {
+        let half_width = self.radius() - (self.position.y - y).abs();
+        if half_width < 0 {
+            None
+        } else {
+            Some((self.position.x - half_width, self.position.x + half_width))
+        }
+    }
+
+    pub fn covers(&self, pos: Point<i64>) -> bool {
+        self.position.manhattan(pos) <= self.radius()
+    }
+}
+
+/// The sensor reports, along with the row checked in part 1 and the size of
+/// the square searched in part 2. These default to the values from the
+/// puzzle, and can be changed after parsing (e.g. to 10 and 20 for the
+/// example).
+pub struct Day15 {
+    pub sensors: Vec<Sensor>,
+    pub row: i64,
+    /// Part 2 searches `0..=bound` in both x and y.
+    pub bound: i64,
+}
+
+impl Solution for Day15 {
+    type Part1 = usize;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let re = Regex::new(
+            r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)\s*$",
+        )
+        .unwrap();
+        let sensors = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| AocError::parse(15, i + 1, line, "expected a sensor report"))?;
+                let num = |n: usize| {
+                    caps[n]
+                        .parse::<i64>()
+                        .map_err(|e| AocError::parse(15, i + 1, line, e.to_string()))
+                };
+                Ok(Sensor {
+                    position: Point::new(num(1)?, num(2)?),
+                    beacon: Point::new(num(3)?, num(4)?),
+                })
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        if sensors.is_empty() {
+            return Err(AocError::invalid(15, "no sensors in input"));
+        }
+        Ok(Day15 {
+            sensors,
+            row: 2_000_000,
+            bound: 4_000_000,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.excluded_positions(self.row))
+    }
+
+    fn part2(&self) -> Result<i64, AocError> {
+        let beacon = self
+            .find_distress_beacon(self.bound)
+            .ok_or_else(|| AocError::invalid(15, "every position in the search area is covered"))?;
+        Ok(beacon.x * 4_000_000 + beacon.y)
+    }
+}
+
+impl Day15 {
+    /// The x ranges covered by at least one sensor on row `y`, as sorted,
+    /// disjoint, inclusive intervals.
+    pub fn row_coverage(&self, y: i64) -> Vec<(i64, i64)> {
+        merge_intervals(self.sensors.iter().filter_map(|s| s.coverage(y)).collect())
+    }
+
+    /// The number of positions on row `y` where a beacon can't be.
+    pub fn excluded_positions(&self, y: i64) -> usize {
+        let covered = self
+            .row_coverage(y)
+            .iter()
+            .map(|&(start, end)| (end - start + 1) as usize)
+            .sum::<usize>();
+        let beacons_on_row = self
+            .sensors
+            .iter()
+            .filter(|s| s.beacon.y == y)
+            .map(|s| s.beacon.x)
+            .collect::<HashSet<_>>()
+            .len();
+        covered - beacons_on_row
+    }
+
+    /// The position in `0..=bound` (in both x and y) that no sensor covers.
+    pub fn find_distress_beacon(&self, bound: i64) -> Option<Point<i64>> {
+        let in_bounds = |p: &Point<i64>| (0..=bound).contains(&p.x) && (0..=bound).contains(&p.y);
+        // If there is only one uncovered position, it must be just outside the
+        // edge of several sensors' ranges, unless it is against the edge of
+        // the search area. So try the crossing points of those edges first,
+        // which are y - x = a and y + x = b for the lines below.
+        let mut rising = Vec::new();
+        let mut falling = Vec::new();
+        for s in &self.sensors {
+            let (x, y, r) = (s.position.x, s.position.y, s.radius() + 1);
+            rising.extend([y - x - r, y - x + r]);
+            falling.extend([y + x - r, y + x + r]);
+        }
+        let candidate = rising
+            .iter()
+            .flat_map(|&a| falling.iter().map(move |&b| (a, b)))
+            .filter(|(a, b)| (a + b) % 2 == 0)
+            .map(|(a, b)| Point::new((b - a) / 2, (a + b) / 2))
+            .filter(in_bounds)
+            .find(|&p| !self.sensors.iter().any(|s| s.covers(p)));
+        candidate.or_else(|| {
+            (0..=bound).find_map(|y| {
+                let mut x = 0;
+                for (start, end) in self.row_coverage(y) {
+                    if start > x {
+                        break;
+                    }
+                    x = x.max(end + 1);
+                }
+                (x <= bound).then_some(Point::new(x, y))
+            })
+        })
+    }
+}
+
+/// Sorts and merges overlapping or adjacent inclusive intervals.
+pub fn merge_intervals(mut intervals: Vec<(i64, i64)>) -> Vec<(i64, i64)> {
+    intervals.sort_unstable();
+    let mut merged: Vec<(i64, i64)> = Vec::with_capacity(intervals.len());
+    for (start, end) in intervals {
+        match merged.last_mut() {
+            Some(last) if start <= last.1 + 1 => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+pub fn day15_part1(filename: &str) -> Result<usize, AocError> {
+    day15_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day15_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day15::parse(input)?.part1()
+}
+
+pub fn day15_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day15::parse_reader(reader)?.part1()
+}
+
+pub fn day15_part2(filename: &str) -> Result<i64, AocError> {
+    day15_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day15_part2_from_str(input: &str) -> Result<i64, AocError> {
+    Day15::parse(input)?.part2()
+}
+
+pub fn day15_part2_from_reader(reader: impl Read) -> Result<i64, AocError> {
+    Day15::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day15_example_test() {
+    let input = "\
+Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16
+Sensor at x=13, y=2: closest beacon is at x=15, y=3
+Sensor at x=12, y=14: closest beacon is at x=10, y=16
+Sensor at x=10, y=20: closest beacon is at x=10, y=16
+Sensor at x=14, y=17: closest beacon is at x=10, y=16
+Sensor at x=8, y=7: closest beacon is at x=2, y=10
+Sensor at x=2, y=0: closest beacon is at x=2, y=10
+Sensor at x=0, y=11: closest beacon is at x=2, y=10
+Sensor at x=20, y=14: closest beacon is at x=25, y=17
+Sensor at x=17, y=20: closest beacon is at x=21, y=22
+Sensor at x=16, y=7: closest beacon is at x=15, y=3
+Sensor at x=14, y=3: closest beacon is at x=15, y=3
+Sensor at x=20, y=1: closest beacon is at x=15, y=3
+";
+    let mut day = Day15::parse(input).unwrap();
+    day.row = 10;
+    day.bound = 20;
+    assert_eq!(26, day.part1().unwrap());
+    assert_eq!(56000011, day.part2().unwrap());
+    assert_eq!(vec![(-2, 24)], day.row_coverage(10));
+
+    // A gap against the edge of the search area, which the fast path misses
+    let corner = "Sensor at x=10, y=10: closest beacon is at x=10, y=20\n";
+    let day = Day15::parse(corner).unwrap();
+    assert_eq!(Some(Point::new(0, 0)), day.find_distress_beacon(20));
+    let covered = "Sensor at x=2, y=2: closest beacon is at x=2, y=6\n";
+    assert_eq!(None, Day15::parse(covered).unwrap().find_distress_beacon(2));
+
+    assert_eq!(
+        vec![(1, 7), (9, 9)],
+        merge_intervals(vec![(4, 7), (1, 3), (9, 9), (2, 5)])
+    );
+}
@@ -0,0 +1,310 @@
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+use std::mem;
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Valve {
+    pub name: String,
+    pub flow: u32,
+    pub tunnels: Vec<String>,
+}
+
+/// The valves worth opening, with the shortest travel time between each pair
+/// of them. The tunnels themselves are no longer needed once this is known.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ValveNetwork {
+    /// The names of the valves with non-zero flow.
+    pub names: Vec<String>,
+    pub flows: Vec<u32>,
+    /// `distances[i][j]` is the number of minutes to walk from valve `i` to
+    /// valve `j`. There is one extra row at the end for the starting valve.
+    pub distances: Vec<Vec<u32>>,
+}
+
+/// The distance between valves with no route between them, small enough that
+/// adding two together can't overflow.
+const UNREACHABLE: u32 = u32::MAX / 2;
+
+/// The subset-based search below needs `2^n` entries for `n` useful valves,
+/// and combining agents takes `3^n` steps per extra agent, which is about
+/// 43 million at this limit.
+const MAX_USEFUL_VALVES: usize = 16;
+
+impl ValveNetwork {
+    /// The network as seen from `start`. Errors if a tunnel leads to a valve
+    /// that isn't in `valves`, if there is no `start` valve, or if there are
+    /// too many valves worth opening to search.
+    pub fn new(valves: &[Valve], start: &str) -> Result<Self, AocError> {
+        let index = valves
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (v.name.as_str(), i))
+            .collect::<HashMap<_, _>>();
+        let n = valves.len();
+        // Floyd-Warshall over every valve, then keep just the useful ones
+        let mut all = vec![vec![UNREACHABLE; n]; n];
+        for (i, valve) in valves.iter().enumerate() {
+            all[i][i] = 0;
+            for tunnel in &valve.tunnels {
+                let &j = index.get(tunnel.as_str()).ok_or_else(|| {
+                    AocError::invalid(
+                        16,
+                        format!("{} leads to unknown valve {}", valve.name, tunnel),
+                    )
+                })?;
+                all[i][j] = 1;
+            }
+        }
+        let &start = index
+            .get(start)
+            .ok_or_else(|| AocError::invalid(16, format!("there is no valve {}", start)))?;
+        if valves.iter().filter(|v| v.flow > 0).count() > MAX_USEFUL_VALVES {
+            return Err(AocError::invalid(
+                16,
+                format!("more than {} valves with non-zero flow", MAX_USEFUL_VALVES),
+            ));
+        }
+        for k in 0..n {
+            for i in 0..n {
+                for j in 0..n {
+                    all[i][j] = all[i][j].min(all[i][k] + all[k][j]);
+                }
+            }
+        }
+        let useful = (0..n).filter(|&i| valves[i].flow > 0).collect::<Vec<_>>();
+        let rows = useful.iter().copied().chain([start]);
+        Ok(ValveNetwork {
+            names: useful.iter().map(|&i| valves[i].name.clone()).collect(),
+            flows: useful.iter().map(|&i| valves[i].flow).collect(),
+            distances: rows
+                .map(|i| useful.iter().map(|&j| all[i][j]).collect())
+                .collect(),
+        })
+    }
+
+    /// The most pressure that `agents` working together can release in
+    /// `minutes`, where moving through a tunnel or opening a valve takes a
+    /// minute. Errors if the total doesn't fit in a `u32`.
+    pub fn max_pressure(&self, minutes: u32, agents: usize) -> Result<u32, AocError> {
+        if agents == 0 {
+            return Ok(0);
+        }
+        let full = (1 << self.flows.len()) - 1;
+        // best[set] is the most one agent can release by opening at most the
+        // valves in `set`
+        let mut best = vec![0; full + 1];
+        self.explore(minutes, &mut best)?;
+        for bit in 0..self.flows.len() {
+            for set in 0..=full {
+                if set & (1 << bit) != 0 {
+                    best[set] = best[set].max(best[set ^ (1 << bit)]);
+                }
+            }
+        }
+        // Agents never need to open the same valve, so each extra agent takes
+        // a disjoint subset of the valves
+        let mut combined = best.clone();
+        for _ in 1..agents {
+            combined = (0..=full)
+                .map(|set| {
+                    let mut most = combined[set];
+                    let mut own = set;
+                    while own > 0 {
+                        let released = best[own]
+                            .checked_add(combined[set ^ own])
+                            .ok_or_else(overflow)?;
+                        most = most.max(released);
+                        own = (own - 1) & set;
+                    }
+                    Ok(most)
+                })
+                .collect::<Result<_, AocError>>()?;
+        }
+        Ok(combined[full])
+    }
+
+    /// Fills in `best[set]` with the most one agent can release by opening
+    /// exactly the valves in `set`, for every set it can open in time.
+    ///
+    /// Different orders of opening valves often end up at the same valve with
+    /// the same valves open and the same time left, so rather than trying
+    /// every order this keeps just the best total for each of those states.
+    /// Every move takes time, so handling the states with the most time left
+    /// first means each is only expanded once.
+    fn explore(&self, minutes: u32, best: &mut [u32]) -> Result<(), AocError> {
+        // states[t] maps (valve, opened) to the most released with t minutes
+        // left. The agent starts on the extra row of `distances`.
+        let mut states = vec![HashMap::new(); minutes as usize + 1];
+        states[minutes as usize].insert((self.flows.len(), 0_usize), 0);
+        for time_left in (0..=minutes).rev() {
+            for ((at, opened), released) in mem::take(&mut states[time_left as usize]) {
+                best[opened] = best[opened].max(released);
+                for next in 0..self.flows.len() {
+                    // Walk there and spend a minute opening it
+                    let cost = self.distances[at][next] + 1;
+                    if opened & (1 << next) == 0 && cost < time_left {
+                        let time_left = time_left - cost;
+                        let released = time_left
+                            .checked_mul(self.flows[next])
+                            .and_then(|flow| flow.checked_add(released))
+                            .ok_or_else(overflow)?;
+                        let state = states[time_left as usize]
+                            .entry((next, opened | (1 << next)))
+                            .or_insert(0);
+                        *state = (*state).max(released);
+                    }
+                }
+            }
+        }
+        Ok(())
+    }
+}
+
+fn overflow() -> AocError {
+    AocError::invalid(16, "the pressure released overflows")
+}
+
+pub struct Day16 {
+    pub valves: Vec<Valve>,
+    /// The network as seen from `AA`, where everyone starts.
+    pub network: ValveNetwork,
+}
+
+impl Solution for Day16 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let re = Regex::new(
+            r"^Valve (\w+) has flow rate=(\d+); tunnels? leads? to valves? (\w+(?:, \w+)*)\s*$",
+        )
+        .unwrap();
+        let valves = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| AocError::parse(16, i + 1, line, "expected a valve report"))?;
+                Ok(Valve {
+                    name: caps[1].to_string(),
+                    flow: caps[2]
+                        .parse()
+                        .map_err(|e| AocError::parse(16, i + 1, line, format!("{}", e)))?,
+                    tunnels: caps[3].split(", ").map(str::to_string).collect(),
+                })
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        let network = ValveNetwork::new(&valves, "AA")?;
+        Ok(Day16 { valves, network })
+    }
+
+    fn part1(&self) -> Result<u32, AocError> {
+        self.network.max_pressure(30, 1)
+    }
+
+    fn part2(&self) -> Result<u32, AocError> {
+        self.network.max_pressure(26, 2)
+    }
+}
+
+pub fn day16_part1(filename: &str) -> Result<u32, AocError> {
+    day16_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day16_part1_from_str(input: &str) -> Result<u32, AocError> {
+    Day16::parse(input)?.part1()
+}
+
+pub fn day16_part1_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day16::parse_reader(reader)?.part1()
+}
+
+pub fn day16_part2(filename: &str) -> Result<u32, AocError> {
+    day16_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day16_part2_from_str(input: &str) -> Result<u32, AocError> {
+    Day16::parse(input)?.part2()
+}
+
+pub fn day16_part2_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day16::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day16_example_test() {
+    let input = "\
+Valve AA has flow rate=0; tunnels lead to valves DD, II, BB
+Valve BB has flow rate=13; tunnels lead to valves CC, AA
+Valve CC has flow rate=2; tunnels lead to valves DD, BB
+Valve DD has flow rate=20; tunnels lead to valves CC, AA, EE
+Valve EE has flow rate=3; tunnels lead to valves FF, DD
+Valve FF has flow rate=0; tunnels lead to valves EE, GG
+Valve GG has flow rate=0; tunnels lead to valves FF, HH
+Valve HH has flow rate=22; tunnel leads to valve GG
+Valve II has flow rate=0; tunnels lead to valves AA, JJ
+Valve JJ has flow rate=21; tunnel leads to valve II
+";
+    assert_eq!(1651, day16_part1_from_str(input).unwrap());
+    assert_eq!(1707, day16_part2_from_str(input).unwrap());
+
+    let day = Day16::parse(input).unwrap();
+    assert_eq!(6, day.network.flows.len());
+    assert_eq!(0, day.network.max_pressure(30, 0).unwrap());
+    assert_eq!(0, day.network.max_pressure(1, 1).unwrap());
+    // With an agent per valve, each is opened as soon as it can be reached
+    assert_eq!(1830, day.network.max_pressure(26, 6).unwrap());
+
+    assert!(Day16::parse("Valve AA has flow rate=0; tunnel leads to valve BB\n").is_err());
+
+    // Every valve is a tunnel away from AA, so there are a huge number of
+    // orders to open them in
+    let names = (1..=15).map(|i| format!("V{:02}", i)).collect::<Vec<_>>();
+    let mut star = format!(
+        "Valve AA has flow rate=0; tunnels lead to valves {}\n",
+        names.join(", ")
+    );
+    for (i, name) in names.iter().enumerate() {
+        star += &format!(
+            "Valve {} has flow rate={}; tunnel leads to valve AA\n",
+            name,
+            i + 1
+        );
+    }
+    let day = Day16::parse(&star).unwrap();
+    assert_eq!(1770, day.network.max_pressure(30, 1).unwrap());
+    assert_eq!(4080, day.network.max_pressure(50, 1).unwrap());
+    assert_eq!(2124, day.network.max_pressure(26, 2).unwrap());
+
+    let too_many = (0..=MAX_USEFUL_VALVES)
+        .map(|i| {
+            format!(
+                "Valve V{:02} has flow rate=1; tunnel leads to valve AA\n",
+                i
+            )
+        })
+        .collect::<String>();
+    let tunnels = (0..=MAX_USEFUL_VALVES)
+        .map(|i| format!("V{:02}", i))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let too_many = format!(
+        "Valve AA has flow rate=0; tunnels lead to valves {}\n{}",
+        tunnels, too_many
+    );
+    assert!(Day16::parse(&too_many).is_err());
+
+    let valves = Day16::parse(input).unwrap().valves;
+    assert!(ValveNetwork::new(&valves, "ZZ").is_err());
+    let huge = "\
+Valve AA has flow rate=0; tunnels lead to valves BB, CC
+Valve BB has flow rate=4000000000; tunnel leads to valve AA
+Valve CC has flow rate=1; tunnel leads to valve AA
+";
+    assert!(day16_part1_from_str(huge).is_err());
+    assert!(day16_part2_from_str(huge).is_err());
+}
@@ -0,0 +1,273 @@
+use crate::geometry::Direction;
+use crate::{AocError, Solution};
+use std::collections::HashMap;
+use std::fs::File;
+use std::io::Read;
+
+const CHAMBER_WIDTH: usize = 7;
+const LEFT_WALL: u8 = 1 << (CHAMBER_WIDTH - 1);
+const RIGHT_WALL: u8 = 1;
+/// How far down from the top of the tower [`Chamber::profile`] looks. Rocks
+/// can't fall much past the top few rows, so anything deeper than this never
+/// affects where they land.
+const PROFILE_DEPTH: usize = 64;
+
+/// The rock shapes in the order they fall, bottom row first, as they appear
+/// when they start to fall (two units from the left wall). Bit 6 is the
+/// leftmost column.
+const ROCKS: [[u8; 4]; 5] = [
+    [0b0011110, 0, 0, 0],
+    [0b0001000, 0b0011100, 0b0001000, 0],
+    [0b0011100, 0b0000100, 0b0000100, 0],
+    [0b0010000, 0b0010000, 0b0010000, 0b0010000],
+    [0b0011000, 0b0011000, 0, 0],
+];
+
+/// The jet pattern, which repeats forever.
+pub struct Day17 {
+    pub jets: Vec<Direction>,
+}
+
+impl Solution for Day17 {
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let line = input.trim_end();
+        let jets = line
+            .chars()
+            .map(|c| match c {
+                '<' => Ok(Direction::Left),
+                '>' => Ok(Direction::Right),
+                _ => Err(AocError::parse(
+                    17,
+                    1,
+                    line,
+                    format!("unexpected character {:?}", c),
+                )),
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        if jets.is_empty() {
+            return Err(AocError::invalid(17, "no jets in input"));
+        }
+        Ok(Day17 { jets })
+    }
+
+    fn part1(&self) -> Result<u64, AocError> {
+        Ok(self.tower_height(2022))
+    }
+
+    fn part2(&self) -> Result<u64, AocError> {
+        Ok(self.tower_height(1_000_000_000_000))
+    }
+}
+
+impl Day17 {
+    /// The height of the tower after `rocks` rocks have fallen.
+    ///
+    /// Once the next rock, the next jet and the shape of the top of the tower
+    /// repeat, so does everything after, so the tower grows by the same
+    /// amount every cycle and only the first cycle needs to be simulated.
+    pub fn tower_height(&self, rocks: u64) -> u64 {
+        let mut chamber = Chamber::new(&self.jets);
+        let mut seen = HashMap::new();
+        // heights[n] is the height after n rocks
+        let mut heights = vec![0];
+        while chamber.rocks < rocks {
+            let state = (
+                chamber.rocks % ROCKS.len() as u64,
+                chamber.next_jet,
+                chamber.profile(),
+            );
+            if let Some(&start) = seen.get(&state) {
+                let cycle_len = chamber.rocks - start;
+                let cycle_growth = chamber.height() as u64 - heights[start as usize];
+                let remaining = rocks - chamber.rocks;
+                let partial =
+                    heights[(start + remaining % cycle_len) as usize] - heights[start as usize];
+                return chamber.height() as u64 + remaining / cycle_len * cycle_growth + partial;
+            }
+            seen.insert(state, chamber.rocks);
+            chamber.drop_rock();
+            heights.push(chamber.height() as u64);
+        }
+        chamber.height() as u64
+    }
+}
+
+/// The chamber and the rocks that have come to rest in it, one bit per
+/// column in each row.
+pub struct Chamber<'a> {
+    jets: &'a [Direction],
+    /// From the floor up. The top row is never empty.
+    rows: Vec<u8>,
+    next_jet: usize,
+    rocks: u64,
+}
+
+impl<'a> Chamber<'a> {
+    pub fn new(jets: &'a [Direction]) -> Self {
+        Chamber {
+            jets,
+            rows: Vec::new(),
+            next_jet: 0,
+            rocks: 0,
+        }
+    }
+
+    pub fn height(&self) -> usize {
+        self.rows.len()
+    }
+
+    /// How many rocks have come to rest so far.
+    pub fn rocks(&self) -> u64 {
+        self.rocks
+    }
+
+    /// Drops the next rock and lets it come to rest.
+    pub fn drop_rock(&mut self) {
+        let mut rock = ROCKS[(self.rocks % ROCKS.len() as u64) as usize];
+        // The row of the bottom of the rock
+        let mut y = self.rows.len() + 3;
+        loop {
+            let jet = self.jets[self.next_jet];
+            self.next_jet = (self.next_jet + 1) % self.jets.len();
+            let pushed = match jet {
+                Direction::Left if rock.iter().all(|&r| r & LEFT_WALL == 0) => {
+                    Some(rock.map(|r| r << 1))
+                }
+                Direction::Right if rock.iter().all(|&r| r & RIGHT_WALL == 0) => {
+                    Some(rock.map(|r| r >> 1))
+                }
+                _ => None,
+            };
+            if let Some(pushed) = pushed.filter(|pushed| !self.collides(pushed, y)) {
+                rock = pushed;
+            }
+            if y == 0 || self.collides(&rock, y - 1) {
+                break;
+            }
+            y -= 1;
+        }
+        for (i, &row) in rock.iter().enumerate().filter(|&(_, &row)| row != 0) {
+            if self.rows.len() <= y + i {
+                self.rows.push(0);
+            }
+            self.rows[y + i] |= row;
+        }
+        self.rocks += 1;
+    }
+
+    fn collides(&self, rock: &[u8; 4], y: usize) -> bool {
+        rock.iter()
+            .zip(self.rows.iter().skip(y))
+            .any(|(&r, &row)| r & row != 0)
+    }
+
+    /// How far down from the top of the tower the first rock in each column
+    /// is, up to [`PROFILE_DEPTH`]. A column that stays empty, e.g. because
+    /// every jet blows the same way, would otherwise get deeper forever.
+    fn profile(&self) -> [usize; CHAMBER_WIDTH] {
+        std::array::from_fn(|col| {
+            let bit = LEFT_WALL >> col;
+            self.rows
+                .iter()
+                .rev()
+                .take(PROFILE_DEPTH)
+                .position(|&row| row & bit != 0)
+                .unwrap_or(self.rows.len().min(PROFILE_DEPTH))
+        })
+    }
+
+    /// Draws the top `rows` rows of the chamber, as in the puzzle
+    /// description. The floor is drawn too if it is in view.
+    pub fn render(&self, rows: usize) -> String {
+        let mut out = String::new();
+        for &row in self.rows.iter().rev().take(rows) {
+            out.push('|');
+            for col in 0..CHAMBER_WIDTH {
+                out.push(if row & (LEFT_WALL >> col) != 0 {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            out.push_str("|\n");
+        }
+        if rows > self.rows.len() {
+            out.push('+');
+            out.push_str(&"-".repeat(CHAMBER_WIDTH));
+            out.push_str("+\n");
+        }
+        out
+    }
+}
+
+pub fn day17_part1(filename: &str) -> Result<u64, AocError> {
+    day17_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day17_part1_from_str(input: &str) -> Result<u64, AocError> {
+    Day17::parse(input)?.part1()
+}
+
+pub fn day17_part1_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day17::parse_reader(reader)?.part1()
+}
+
+pub fn day17_part2(filename: &str) -> Result<u64, AocError> {
+    day17_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day17_part2_from_str(input: &str) -> Result<u64, AocError> {
+    Day17::parse(input)?.part2()
+}
+
+pub fn day17_part2_from_reader(reader: impl Read) -> Result<u64, AocError> {
+    Day17::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day17_example_test() {
+    let input = ">>><<><>><<<>><>>><<<>>><<<><<<>><>><<>>\n";
+    assert_eq!(3068, day17_part1_from_str(input).unwrap());
+    assert_eq!(1514285714288, day17_part2_from_str(input).unwrap());
+
+    let day = Day17::parse(input).unwrap();
+    let mut chamber = Chamber::new(&day.jets);
+    for _ in 0..3 {
+        chamber.drop_rock();
+    }
+    assert_eq!(6, chamber.height());
+    assert_eq!(
+        "\
+|..#....|
+|..#....|
+|####...|
+|..###..|
+|...#...|
+|..####.|
++-------+
+",
+        chamber.render(10)
+    );
+    assert_eq!("|..#....|\n", chamber.render(1));
+
+    // Simulating directly agrees with extrapolating from a cycle
+    let mut chamber = Chamber::new(&day.jets);
+    for _ in 0..5000 {
+        chamber.drop_rock();
+    }
+    assert_eq!(chamber.height() as u64, day.tower_height(5000));
+
+    // Jets that all blow one way leave a column empty all the way down
+    for input in [">\n", "<\n", "<<>\n"] {
+        let day = Day17::parse(input).unwrap();
+        let mut chamber = Chamber::new(&day.jets);
+        for _ in 0..5000 {
+            chamber.drop_rock();
+        }
+        assert_eq!(chamber.height() as u64, day.tower_height(5000), "{}", input);
+        assert!(day.part2().is_ok());
+    }
+}
@@ -0,0 +1,147 @@
+use crate::geometry::Point3;
+use crate::search::flood_fill;
+use crate::{AocError, Solution};
+use std::collections::HashSet;
+use std::fs::File;
+use std::io::Read;
+
+/// The unit cubes of lava, by position. The input is `i32`s, but they're
+/// kept as `i64`s so that the air around the droplet is always in range.
+pub struct Day18 {
+    pub cubes: HashSet<Point3<i64>>,
+}
+
+impl Solution for Day18 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let cubes = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let coords = line
+                    .trim()
+                    .split(',')
+                    .map(|c| {
+                        c.parse::<i32>()
+                            .map_err(|e| AocError::parse(18, i + 1, line, e.to_string()))
+                    })
+                    .collect::<Result<Vec<_>, AocError>>()?;
+                match coords[..] {
+                    [x, y, z] => Ok(Point3::new(x.into(), y.into(), z.into())),
+                    _ => Err(AocError::parse(18, i + 1, line, "expected x,y,z")),
+                }
+            })
+            .collect::<Result<HashSet<_>, AocError>>()?;
+        if cubes.is_empty() {
+            return Err(AocError::invalid(18, "no cubes in input"));
+        }
+        Ok(Day18 { cubes })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self
+            .cubes
+            .iter()
+            .flat_map(|cube| cube.neighbours6())
+            .filter(|side| !self.cubes.contains(side))
+            .count())
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        // Fill the air around the droplet, in a box one bigger than it on
+        // every side so that the air can get all the way round
+        let empty = || AocError::invalid(18, "no cubes in input");
+        let min = |axis: fn(&Point3<i64>) -> i64| {
+            self.cubes
+                .iter()
+                .map(axis)
+                .min()
+                .ok_or_else(empty)
+                .map(|v| v - 1)
+        };
+        let max = |axis: fn(&Point3<i64>) -> i64| {
+            self.cubes
+                .iter()
+                .map(axis)
+                .max()
+                .ok_or_else(empty)
+                .map(|v| v + 1)
+        };
+        let low = Point3::new(min(|p| p.x)?, min(|p| p.y)?, min(|p| p.z)?);
+        let high = Point3::new(max(|p| p.x)?, max(|p| p.y)?, max(|p| p.z)?);
+        let in_box = |p: &Point3<i64>| {
+            (low.x..=high.x).contains(&p.x)
+                && (low.y..=high.y).contains(&p.y)
+                && (low.z..=high.z).contains(&p.z)
+        };
+        let outside = flood_fill([low], |air| {
+            air.neighbours6()
+                .into_iter()
+                .filter(|next| in_box(next) && !self.cubes.contains(next))
+        });
+        Ok(outside
+            .iter()
+            .flat_map(|air| air.neighbours6())
+            .filter(|side| self.cubes.contains(side))
+            .count())
+    }
+}
+
+pub fn day18_part1(filename: &str) -> Result<usize, AocError> {
+    day18_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day18_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day18::parse(input)?.part1()
+}
+
+pub fn day18_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day18::parse_reader(reader)?.part1()
+}
+
+pub fn day18_part2(filename: &str) -> Result<usize, AocError> {
+    day18_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day18_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day18::parse(input)?.part2()
+}
+
+pub fn day18_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day18::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day18_example_test() {
+    assert_eq!(10, day18_part1_from_str("1,1,1\n2,1,1\n").unwrap());
+    let input = "\
+2,2,2
+1,2,2
+3,2,2
+2,1,2
+2,3,2
+2,2,1
+2,2,3
+2,2,4
+2,2,6
+1,2,5
+3,2,5
+2,1,5
+2,3,5
+";
+    assert_eq!(64, day18_part1_from_str(input).unwrap());
+    assert_eq!(58, day18_part2_from_str(input).unwrap());
+    assert!(day18_part1_from_str("1,2\n").is_err());
+
+    // The air around cubes at the edges of the i32 range is still in range
+    let edges = "2147483647,0,0\n-2147483648,0,0\n";
+    assert_eq!(12, day18_part1_from_str(edges).unwrap());
+    let corner = "2147483647,2147483647,2147483647\n";
+    assert_eq!(6, day18_part2_from_str(corner).unwrap());
+    let empty = Day18 {
+        cubes: HashSet::new(),
+    };
+    assert!(empty.part2().is_err());
+}
@@ -0,0 +1,203 @@
+use crate::{AocError, Solution};
+use regex::Regex;
+use std::fs::File;
+use std::io::Read;
+use std::thread;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Blueprint {
+    pub id: u32,
+    /// `costs[robot][resource]`, with robots and resources both in the order
+    /// ore, clay, obsidian, geode. Nothing costs geodes.
+    pub costs: [[u32; 4]; 4],
+}
+
+impl Blueprint {
+    /// The most geodes that can be opened in `minutes`, starting with a
+    /// single ore robot.
+    pub fn max_geodes(&self, minutes: u32) -> u32 {
+        // There's no point having more robots of a kind than can be spent in
+        // a minute, since only one robot can be built per minute
+        let mut max_robots = [u32::MAX; 4];
+        for resource in [ORE, CLAY, OBSIDIAN] {
+            max_robots[resource] = self.costs.iter().map(|c| c[resource]).max().unwrap();
+        }
+        let mut best = 0;
+        self.search(minutes, [1, 0, 0, 0], [0; 4], &max_robots, &mut best);
+        best
+    }
+
+    /// Branches on which robot to build next, skipping straight to the
+    /// minute it's built rather than deciding minute by minute.
+    fn search(
+        &self,
+        time_left: u32,
+        robots: [u32; 4],
+        stock: [u32; 4],
+        max_robots: &[u32; 4],
+        best: &mut u32,
+    ) {
+        let idle_geodes = stock[GEODE] + robots[GEODE] * time_left;
+        *best = (*best).max(idle_geodes);
+        // Even building a geode robot every remaining minute can't beat the
+        // best so far
+        if idle_geodes + time_left * time_left.saturating_sub(1) / 2 <= *best {
+            return;
+        }
+        for robot in [GEODE, OBSIDIAN, CLAY, ORE] {
+            if robots[robot] >= max_robots[robot] {
+                continue;
+            }
+            let mut wait = 0;
+            let mut affordable = true;
+            for resource in [ORE, CLAY, OBSIDIAN] {
+                let cost = self.costs[robot][resource];
+                if cost > stock[resource] {
+                    if robots[resource] == 0 {
+                        affordable = false;
+                        break;
+                    }
+                    wait = wait.max((cost - stock[resource]).div_ceil(robots[resource]));
+                }
+            }
+            // Spend `wait` minutes collecting and one more building
+            let elapsed = wait + 1;
+            if !affordable || elapsed >= time_left {
+                continue;
+            }
+            let stock =
+                std::array::from_fn(|r| stock[r] + robots[r] * elapsed - self.costs[robot][r]);
+            let mut robots = robots;
+            robots[robot] += 1;
+            self.search(time_left - elapsed, robots, stock, max_robots, best);
+        }
+    }
+}
+
+/// [`Blueprint::max_geodes`] for each blueprint, which are independent so
+/// are spread across threads.
+pub fn max_geodes_all(blueprints: &[Blueprint], minutes: u32) -> Vec<u32> {
+    let threads = thread::available_parallelism().map_or(1, |n| n.get());
+    let chunk_size = blueprints.len().div_ceil(threads).max(1);
+    thread::scope(|s| {
+        let handles = blueprints
+            .chunks(chunk_size)
+            .map(|chunk| {
+                s.spawn(move || {
+                    chunk
+                        .iter()
+                        .map(|b| b.max_geodes(minutes))
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        handles
+            .into_iter()
+            .flat_map(|h| h.join().unwrap())
+            .collect()
+    })
+}
+
+pub struct Day19 {
+    pub blueprints: Vec<Blueprint>,
+}
+
+impl Solution for Day19 {
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let re = Regex::new(concat!(
+            r"^Blueprint (?P<id>\d+): ",
+            r"Each ore robot costs (?P<ore_ore>\d+) ore\. ",
+            r"Each clay robot costs (?P<clay_ore>\d+) ore\. ",
+            r"Each obsidian robot costs (?P<obs_ore>\d+) ore and (?P<obs_clay>\d+) clay\. ",
+            r"Each geode robot costs (?P<geo_ore>\d+) ore and (?P<geo_obs>\d+) obsidian\.\s*$",
+        ))
+        .unwrap();
+        let blueprints = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let caps = re
+                    .captures(line)
+                    .ok_or_else(|| AocError::parse(19, i + 1, line, "expected a blueprint"))?;
+                let num = |name: &str| {
+                    caps[name]
+                        .parse::<u32>()
+                        .map_err(|e| AocError::parse(19, i + 1, line, e.to_string()))
+                };
+                Ok(Blueprint {
+                    id: num("id")?,
+                    costs: [
+                        [num("ore_ore")?, 0, 0, 0],
+                        [num("clay_ore")?, 0, 0, 0],
+                        [num("obs_ore")?, num("obs_clay")?, 0, 0],
+                        [num("geo_ore")?, 0, num("geo_obs")?, 0],
+                    ],
+                })
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        if blueprints.is_empty() {
+            return Err(AocError::invalid(19, "no blueprints in input"));
+        }
+        Ok(Day19 { blueprints })
+    }
+
+    fn part1(&self) -> Result<u32, AocError> {
+        Ok(self
+            .blueprints
+            .iter()
+            .zip(max_geodes_all(&self.blueprints, 24))
+            .map(|(b, geodes)| b.id * geodes)
+            .sum())
+    }
+
+    fn part2(&self) -> Result<u32, AocError> {
+        let first = &self.blueprints[..self.blueprints.len().min(3)];
+        Ok(max_geodes_all(first, 32).into_iter().product())
+    }
+}
+
+pub fn day19_part1(filename: &str) -> Result<u32, AocError> {
+    day19_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day19_part1_from_str(input: &str) -> Result<u32, AocError> {
+    Day19::parse(input)?.part1()
+}
+
+pub fn day19_part1_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day19::parse_reader(reader)?.part1()
+}
+
+pub fn day19_part2(filename: &str) -> Result<u32, AocError> {
+    day19_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day19_part2_from_str(input: &str) -> Result<u32, AocError> {
+    Day19::parse(input)?.part2()
+}
+
+pub fn day19_part2_from_reader(reader: impl Read) -> Result<u32, AocError> {
+    Day19::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day19_example_test() {
+    let input = "\
+Blueprint 1: Each ore robot costs 4 ore. Each clay robot costs 2 ore. Each obsidian robot costs 3 ore and 14 clay. Each geode robot costs 2 ore and 7 obsidian.
+Blueprint 2: Each ore robot costs 2 ore. Each clay robot costs 3 ore. Each obsidian robot costs 3 ore and 8 clay. Each geode robot costs 3 ore and 12 obsidian.
+";
+    assert_eq!(33, day19_part1_from_str(input).unwrap());
+    assert_eq!(56 * 62, day19_part2_from_str(input).unwrap());
+
+    let day = Day19::parse(input).unwrap();
+    assert_eq!(vec![9, 12], max_geodes_all(&day.blueprints, 24));
+    assert!(day19_part1_from_str("Blueprint 1: Each ore robot costs 4 ore.\n").is_err());
+}
@@ -0,0 +1,149 @@
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+/// The encrypted file, in its original order.
+pub struct Day20 {
+    pub numbers: Vec<i64>,
+}
+
+impl Solution for Day20 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.trim()
+                    .parse::<i64>()
+                    .map_err(|e| AocError::parse(20, i + 1, line, e.to_string()))
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        if !numbers.contains(&0) {
+            return Err(AocError::invalid(20, "there is no 0 in the file"));
+        }
+        Ok(Day20 { numbers })
+    }
+
+    fn part1(&self) -> Result<i64, AocError> {
+        grove_coordinates(&mix(&self.numbers, 1, 1)?)
+    }
+
+    fn part2(&self) -> Result<i64, AocError> {
+        grove_coordinates(&mix(&self.numbers, DECRYPTION_KEY, 10)?)
+    }
+}
+
+/// Multiplies every number by `key`, then mixes the list `rounds` times. The
+/// list is circular, so only the order of the result matters and not which
+/// number it starts from.
+///
+/// Each round moves every number, in their original order, forward (or
+/// backward, if negative) around the circular list by its value. Numbers are
+/// tracked by their original position, so duplicates are fine. Errors if
+/// multiplying by the key overflows.
+pub fn mix(numbers: &[i64], key: i64, rounds: usize) -> Result<Vec<i64>, AocError> {
+    let numbers = numbers
+        .iter()
+        .map(|&n| n.checked_mul(key))
+        .collect::<Option<Vec<_>>>()
+        .ok_or_else(|| AocError::invalid(20, "applying the decryption key overflows"))?;
+    if numbers.len() < 2 {
+        return Ok(numbers);
+    }
+    // order[i] is the original index of the number now at position i
+    let mut order = (0..numbers.len()).collect::<Vec<_>>();
+    // A number moving all the way round passes the other len - 1 numbers
+    let cycle = numbers.len() as i64 - 1;
+    for _ in 0..rounds {
+        for (original, &n) in numbers.iter().enumerate() {
+            let from = order.iter().position(|&i| i == original).unwrap();
+            order.remove(from);
+            let to = (from as i64 + n.rem_euclid(cycle)).rem_euclid(cycle) as usize;
+            order.insert(to, original);
+        }
+    }
+    Ok(order.into_iter().map(|i| numbers[i]).collect())
+}
+
+/// The sum of the 1000th, 2000th and 3000th numbers after the 0.
+pub fn grove_coordinates(mixed: &[i64]) -> Result<i64, AocError> {
+    let zero = mixed
+        .iter()
+        .position(|&n| n == 0)
+        .ok_or_else(|| AocError::invalid(20, "there is no 0 in the file"))?;
+    [1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .try_fold(0_i64, |sum, n| sum.checked_add(n))
+        .ok_or_else(|| AocError::invalid(20, "the grove coordinates overflow"))
+}
+
+pub fn day20_part1(filename: &str) -> Result<i64, AocError> {
+    day20_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day20_part1_from_str(input: &str) -> Result<i64, AocError> {
+    Day20::parse(input)?.part1()
+}
+
+pub fn day20_part1_from_reader(reader: impl Read) -> Result<i64, AocError> {
+    Day20::parse_reader(reader)?.part1()
+}
+
+pub fn day20_part2(filename: &str) -> Result<i64, AocError> {
+    day20_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day20_part2_from_str(input: &str) -> Result<i64, AocError> {
+    Day20::parse(input)?.part2()
+}
+
+pub fn day20_part2_from_reader(reader: impl Read) -> Result<i64, AocError> {
+    Day20::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day20_example_test() {
+    let input = "1\n2\n-3\n3\n-2\n0\n4\n";
+    assert_eq!(3, day20_part1_from_str(input).unwrap());
+    assert_eq!(1623178306, day20_part2_from_str(input).unwrap());
+
+    // Lists are circular, so compare from the 0
+    let from_zero = |mixed: Vec<i64>| {
+        let zero = mixed.iter().position(|&n| n == 0).unwrap();
+        [&mixed[zero..], &mixed[..zero]].concat()
+    };
+    let numbers = [1, 2, -3, 3, -2, 0, 4];
+    assert_eq!(
+        vec![0, 3, -2, 1, 2, -3, 4],
+        from_zero(mix(&numbers, 1, 1).unwrap())
+    );
+    assert_eq!(
+        vec![
+            0,
+            -2434767459,
+            1623178306,
+            3246356612,
+            -1623178306,
+            2434767459,
+            811589153
+        ],
+        from_zero(mix(&numbers, DECRYPTION_KEY, 10).unwrap())
+    );
+    // Duplicates each move by their own value, in their own turn
+    assert_eq!(vec![0, 1, 1], from_zero(mix(&[1, 1, 0], 1, 1).unwrap()));
+    assert!(day20_part1_from_str("1\n2\n").is_err());
+    assert!(grove_coordinates(&[1, 2]).is_err());
+    assert!(mix(&[i64::MAX, 0], 2, 1).is_err());
+    assert!(day20_part2_from_str("9223372036854775807\n0\n").is_err());
+    // Moving by a huge amount only depends on it modulo len - 1
+    assert_eq!(
+        vec![0, 1, i64::MAX],
+        from_zero(mix(&[1, i64::MAX, 0], 1, 1).unwrap())
+    );
+}
@@ -0,0 +1,417 @@
+use crate::{AocError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Sub,
+    Mul,
+    Div,
+}
+
+impl Operator {
+    /// `None` on overflow or division by zero.
+    pub fn apply(self, lhs: i64, rhs: i64) -> Option<i64> {
+        match self {
+            Operator::Add => lhs.checked_add(rhs),
+            Operator::Sub => lhs.checked_sub(rhs),
+            Operator::Mul => lhs.checked_mul(rhs),
+            Operator::Div => lhs.checked_div(rhs),
+        }
+    }
+}
+
+impl fmt::Display for Operator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let symbol = match self {
+            Operator::Add => "+",
+            Operator::Sub => "-",
+            Operator::Mul => "*",
+            Operator::Div => "/",
+        };
+        write!(f, "{}", symbol)
+    }
+}
+
+/// What a monkey yells: either a number, or the result of an operation on
+/// what two other monkeys yell.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Job {
+    Number(i64),
+    Operation(String, Operator, String),
+}
+
+/// An arithmetic expression, possibly with unknowns in it.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Expr {
+    Number(i64),
+    Variable(String),
+    Binary(Box<Expr>, Operator, Box<Expr>),
+}
+
+impl fmt::Display for Expr {
+    /// Writes the expression in infix form, fully bracketed.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Expr::Number(n) => write!(f, "{}", n),
+            Expr::Variable(name) => write!(f, "{}", name),
+            Expr::Binary(lhs, op, rhs) => write!(f, "({} {} {})", lhs, op, rhs),
+        }
+    }
+}
+
+impl Expr {
+    /// The value of the expression, if it has no unknowns and can be
+    /// evaluated without overflow or dividing by zero.
+    pub fn evaluate(&self) -> Option<i64> {
+        match self {
+            Expr::Number(n) => Some(*n),
+            Expr::Variable(_) => None,
+            Expr::Binary(lhs, op, rhs) => op.apply(lhs.evaluate()?, rhs.evaluate()?),
+        }
+    }
+
+    /// Evaluates every subexpression that has no unknowns.
+    pub fn simplify(self) -> Expr {
+        match self {
+            Expr::Binary(lhs, op, rhs) => {
+                let (lhs, rhs) = (lhs.simplify(), rhs.simplify());
+                match (&lhs, &rhs) {
+                    (&Expr::Number(a), &Expr::Number(b)) => match op.apply(a, b) {
+                        Some(n) => Expr::Number(n),
+                        None => Expr::Binary(Box::new(lhs), op, Box::new(rhs)),
+                    },
+                    _ => Expr::Binary(Box::new(lhs), op, Box::new(rhs)),
+                }
+            }
+            expr => expr,
+        }
+    }
+
+    /// Replaces the unknown `name` with `value`.
+    pub fn substitute(self, name: &str, value: i64) -> Expr {
+        match self {
+            Expr::Variable(var) if var == name => Expr::Number(value),
+            Expr::Binary(lhs, op, rhs) => Expr::Binary(
+                Box::new(lhs.substitute(name, value)),
+                op,
+                Box::new(rhs.substitute(name, value)),
+            ),
+            expr => expr,
+        }
+    }
+
+    /// A value of the single unknown that makes this expression equal
+    /// `target`, found by undoing each operation on the way down to it.
+    /// `None` if there isn't exactly one unknown or there is no integer
+    /// solution. Where several values work, as for `x / 2 = 3` or
+    /// `c / x = 0`, any one of them may be returned.
+    pub fn solve(&self, target: i64) -> Option<i64> {
+        self.solve_between(target.into(), target.into())
+    }
+
+    /// A value of the single unknown that makes this expression somewhere
+    /// from `lo` to `hi` inclusive. Integer division maps a whole range of
+    /// numbers to the same result, so the range of values that work is
+    /// passed down rather than just one. The bounds are `i128`s so that
+    /// undoing an operation on an `i64` can't overflow.
+    fn solve_between(&self, lo: i128, hi: i128) -> Option<i64> {
+        // Everything that is evaluated has to fit in an i64
+        let lo = lo.max(i64::MIN.into());
+        let hi = hi.min(i64::MAX.into());
+        if lo > hi {
+            return None;
+        }
+        match self {
+            Expr::Number(_) => None,
+            Expr::Variable(_) => Some(0.clamp(lo, hi) as i64),
+            Expr::Binary(lhs, op, rhs) => match (lhs.evaluate(), rhs.evaluate()) {
+                // x op c is from lo to hi
+                (None, Some(c)) => {
+                    let c = i128::from(c);
+                    let (lo, hi) = match op {
+                        Operator::Add => (lo - c, hi - c),
+                        Operator::Sub => (lo + c, hi + c),
+                        Operator::Mul => factor_range(c, lo, hi),
+                        Operator::Div => dividend_range(c, lo, hi),
+                    };
+                    lhs.solve_between(lo, hi)
+                }
+                // c op x is from lo to hi
+                (Some(c), None) => {
+                    let c = i128::from(c);
+                    let (lo, hi) = match op {
+                        Operator::Add => (lo - c, hi - c),
+                        Operator::Sub => (c - hi, c - lo),
+                        Operator::Mul => factor_range(c, lo, hi),
+                        Operator::Div => {
+                            // c / x only depends on the size of x, and its
+                            // sign flips with the signs of c and x
+                            let (same, flipped) =
+                                (divisor_range(c, lo, hi), divisor_range(c, -hi, -lo));
+                            let (positive, negative) = if c >= 0 {
+                                (same, flipped)
+                            } else {
+                                (flipped, same)
+                            };
+                            return rhs
+                                .solve_between(positive.0, positive.1)
+                                .or_else(|| rhs.solve_between(-negative.1, -negative.0));
+                        }
+                    };
+                    rhs.solve_between(lo, hi)
+                }
+                _ => None,
+            },
+        }
+    }
+}
+
+/// The `x` for which `x * c` is from `lo` to `hi`.
+fn factor_range(c: i128, lo: i128, hi: i128) -> (i128, i128) {
+    match c {
+        0 if lo <= 0 && hi >= 0 => (i64::MIN.into(), i64::MAX.into()),
+        0 => (1, 0),
+        _ if c < 0 => factor_range(-c, -hi, -lo),
+        _ => (-(-lo).div_euclid(c), hi.div_euclid(c)),
+    }
+}
+
+/// The `x` for which `x / c`, rounding towards zero, is from `lo` to `hi`.
+fn dividend_range(c: i128, lo: i128, hi: i128) -> (i128, i128) {
+    match c {
+        0 => (1, 0),
+        _ if c < 0 => {
+            let (lo, hi) = dividend_range(-c, lo, hi);
+            (-hi, -lo)
+        }
+        // Each quotient q comes from c numbers, running away from zero from
+        // q * c, except that 0 comes from the numbers either side of it too
+        _ => (
+            if lo > 0 { lo * c } else { lo * c - (c - 1) },
+            if hi >= 0 { hi * c + (c - 1) } else { hi * c },
+        ),
+    }
+}
+
+/// The positive `x` for which `|c| / x` is from `lo` to `hi`.
+fn divisor_range(c: i128, lo: i128, hi: i128) -> (i128, i128) {
+    let c = c.abs();
+    // |c| / x only goes from |c| down to 0 as x grows
+    let (lo, hi) = (lo.max(0), hi.min(c));
+    if lo > hi {
+        return (1, 0);
+    }
+    let most = if lo == 0 { i64::MAX.into() } else { c / lo };
+    (c / (hi + 1) + 1, most)
+}
+
+pub struct Day21 {
+    pub monkeys: HashMap<String, Job>,
+}
+
+impl Solution for Day21 {
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let monkeys = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                let err = |reason: &str| AocError::parse(21, i + 1, line, reason);
+                let (name, job) = line
+                    .split_once(": ")
+                    .ok_or_else(|| err("expected <name>: <job>"))?;
+                let job = match job.split_whitespace().collect::<Vec<_>>()[..] {
+                    [n] => Job::Number(n.parse().map_err(|_| err("invalid number"))?),
+                    [lhs, op, rhs] => {
+                        let op = match op {
+                            "+" => Operator::Add,
+                            "-" => Operator::Sub,
+                            "*" => Operator::Mul,
+                            "/" => Operator::Div,
+                            _ => return Err(err("unknown operator")),
+                        };
+                        Job::Operation(lhs.to_string(), op, rhs.to_string())
+                    }
+                    _ => return Err(err("expected a number or <a> <op> <b>")),
+                };
+                Ok((name.to_string(), job))
+            })
+            .collect::<Result<HashMap<_, _>, AocError>>()?;
+        Ok(Day21 { monkeys })
+    }
+
+    fn part1(&self) -> Result<i64, AocError> {
+        self.expression("root", None)?
+            .evaluate()
+            .ok_or_else(|| AocError::invalid(21, "root's number overflows or divides by zero"))
+    }
+
+    fn part2(&self) -> Result<i64, AocError> {
+        let Some(Job::Operation(lhs, _, rhs)) = self.monkeys.get("root") else {
+            return Err(AocError::invalid(21, "root doesn't compare two monkeys"));
+        };
+        let lhs = self.expression(lhs, Some("humn"))?.simplify();
+        let rhs = self.expression(rhs, Some("humn"))?.simplify();
+        let solution = match (lhs.evaluate(), rhs.evaluate()) {
+            (None, Some(target)) => lhs.solve(target),
+            (Some(target), None) => rhs.solve(target),
+            _ => None,
+        };
+        // Check the answer really does make both sides equal
+        let solution = solution.filter(|&humn| {
+            let lhs = lhs.clone().substitute("humn", humn).evaluate();
+            let rhs = rhs.clone().substitute("humn", humn).evaluate();
+            lhs.is_some() && lhs == rhs
+        });
+        solution.ok_or_else(|| AocError::invalid(21, "can't find a number for humn to yell"))
+    }
+}
+
+impl Day21 {
+    /// The expression for what monkey `name` yells. If `unknown` is given,
+    /// that monkey is left as a variable instead of looked up.
+    pub fn expression(&self, name: &str, unknown: Option<&str>) -> Result<Expr, AocError> {
+        self.build_expression(name, unknown, &mut HashSet::new())
+    }
+
+    fn build_expression<'a>(
+        &'a self,
+        name: &'a str,
+        unknown: Option<&str>,
+        visiting: &mut HashSet<&'a str>,
+    ) -> Result<Expr, AocError> {
+        if unknown == Some(name) {
+            return Ok(Expr::Variable(name.to_string()));
+        }
+        let job = self
+            .monkeys
+            .get(name)
+            .ok_or_else(|| AocError::invalid(21, format!("no monkey named {}", name)))?;
+        if !visiting.insert(name) {
+            return Err(AocError::invalid(21, format!("{} depends on itself", name)));
+        }
+        let expr = match job {
+            Job::Number(n) => Expr::Number(*n),
+            Job::Operation(lhs, op, rhs) => Expr::Binary(
+                Box::new(self.build_expression(lhs, unknown, visiting)?),
+                *op,
+                Box::new(self.build_expression(rhs, unknown, visiting)?),
+            ),
+        };
+        visiting.remove(name);
+        Ok(expr)
+    }
+}
+
+pub fn day21_part1(filename: &str) -> Result<i64, AocError> {
+    day21_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day21_part1_from_str(input: &str) -> Result<i64, AocError> {
+    Day21::parse(input)?.part1()
+}
+
+pub fn day21_part1_from_reader(reader: impl Read) -> Result<i64, AocError> {
+    Day21::parse_reader(reader)?.part1()
+}
+
+pub fn day21_part2(filename: &str) -> Result<i64, AocError> {
+    day21_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day21_part2_from_str(input: &str) -> Result<i64, AocError> {
+    Day21::parse(input)?.part2()
+}
+
+pub fn day21_part2_from_reader(reader: impl Read) -> Result<i64, AocError> {
+    Day21::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day21_example_test() {
+    let input = "\
+root: pppw + sjmn
+dbpl: 5
+cczh: sllz + lgvd
+zczc: 2
+ptdq: humn - dvpt
+dvpt: 3
+lfqf: 4
+humn: 5
+ljgn: 2
+sjmn: drzm * dbpl
+sllz: 4
+pppw: cczh / lfqf
+lgvd: ljgn * ptdq
+drzm: hmdt - zczc
+hmdt: 32
+";
+    assert_eq!(152, day21_part1_from_str(input).unwrap());
+    assert_eq!(301, day21_part2_from_str(input).unwrap());
+
+    let day = Day21::parse(input).unwrap();
+    let pppw = day.expression("pppw", Some("humn")).unwrap();
+    assert_eq!("((4 + (2 * (humn - 3))) / 4)", pppw.to_string());
+    assert_eq!(
+        "((4 + (2 * (humn - 3))) / 4)",
+        pppw.clone().simplify().to_string()
+    );
+    assert_eq!(
+        "(32 - 2)",
+        day.expression("drzm", None).unwrap().to_string()
+    );
+    assert_eq!(
+        Expr::Number(30),
+        day.expression("drzm", None).unwrap().simplify()
+    );
+    assert_eq!(Some(301), pppw.solve(150));
+
+    assert!(day21_part1_from_str("root: a + b\na: b * 2\nb: a - 1\n").is_err());
+
+    // humn as the divisor
+    let divide = |n: i64| {
+        Expr::Binary(
+            Box::new(Expr::Number(n)),
+            Operator::Div,
+            Box::new(Expr::Variable("humn".to_string())),
+        )
+    };
+    assert_eq!(None, divide(11).solve(4));
+    assert_eq!(Some(2), divide(11).solve(5));
+    assert_eq!(Some(-3), divide(11).solve(-3));
+    assert_eq!(Some(0), divide(11).solve(0).map(|x| 11 / x));
+    assert_eq!(None, divide(11).solve(12));
+    let input = "root: aaaa + bbbb\naaaa: cccc / humn\ncccc: 11\nbbbb: 4\nhumn: 1\n";
+    assert!(day21_part2_from_str(input).is_err());
+    assert_eq!(
+        2,
+        day21_part2_from_str(&input.replace("bbbb: 4", "bbbb: 5")).unwrap()
+    );
+    assert_eq!(Some(-2), divide(-11).solve(5));
+    assert_eq!(Some(2), divide(-11).solve(-5));
+    assert_eq!(None, divide(0).solve(1));
+    assert!(divide(0).solve(0).is_some_and(|x| x != 0));
+
+    // Integer division means several numbers can work, and the product only
+    // has to land somewhere in the range that divides down to the target
+    let input = "root: a + b\na: d / e\nd: humn * f\nf: 3\ne: 4\nb: 4\nhumn: 1\n";
+    assert_eq!(6, day21_part2_from_str(input).unwrap());
+    let divided =
+        |lhs: Expr, c: i64| Expr::Binary(Box::new(lhs), Operator::Div, Box::new(Expr::Number(c)));
+    let humn = || Expr::Variable("humn".to_string());
+    assert_eq!(Some(6), divided(humn(), 2).solve(3));
+    assert_eq!(Some(-6), divided(humn(), 2).solve(-3));
+    assert_eq!(Some(0), divided(humn(), -2).solve(0));
+    assert_eq!(None, divided(humn(), -1).solve(i64::MIN));
+    let times = |c: i64| Expr::Binary(Box::new(humn()), Operator::Mul, Box::new(Expr::Number(c)));
+    assert_eq!(None, times(-1).solve(i64::MIN));
+    assert_eq!(Some(i64::MIN), times(1).solve(i64::MIN));
+    assert_eq!(Some(-4), times(-3).solve(12));
+    assert_eq!(None, times(3).solve(13));
+}
@@ -0,0 +1,407 @@
+use crate::common::split_blocks;
+use crate::geometry::{Direction, Point, Point3};
+use crate::grid::Grid;
+use crate::{AocError, Solution};
+use std::collections::{HashMap, VecDeque};
+use std::fs::File;
+use std::io::Read;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    /// Off the edge of the map.
+    Void,
+    Open,
+    Wall,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Move {
+    Forward(u32),
+    Left,
+    Right,
+}
+
+/// The board, padded out with `Void` to be rectangular, and the path to walk.
+pub struct Day22 {
+    pub board: Grid<Tile>,
+    pub path: Vec<Move>,
+}
+
+impl Solution for Day22 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let blocks = split_blocks(input);
+        let [(_, board), (path_line, path)] = blocks[..] else {
+            return Err(AocError::invalid(
+                22,
+                "expected the board and the path separated by a blank line",
+            ));
+        };
+        let width = board.lines().map(|l| l.chars().count()).max().unwrap_or(0);
+        let height = board.lines().count();
+        if width == 0 {
+            return Err(AocError::invalid(22, "the board is empty"));
+        }
+        let mut cells = Vec::with_capacity(width * height);
+        for (i, line) in board.lines().enumerate() {
+            for c in line.chars() {
+                cells.push(match c {
+                    ' ' => Tile::Void,
+                    '.' => Tile::Open,
+                    '#' => Tile::Wall,
+                    _ => {
+                        return Err(AocError::parse(
+                            22,
+                            i + 1,
+                            line,
+                            format!("unexpected character {:?}", c),
+                        ))
+                    }
+                });
+            }
+            cells.extend((line.chars().count()..width).map(|_| Tile::Void));
+        }
+        let board = Grid::from_vec(width, height, cells);
+
+        let path = path.trim_end();
+        let mut moves = Vec::new();
+        let mut steps = None;
+        for c in path.chars() {
+            if let Some(digit) = c.to_digit(10) {
+                steps = Some(steps.unwrap_or(0) * 10 + digit);
+                continue;
+            }
+            if let Some(steps) = steps.take() {
+                moves.push(Move::Forward(steps));
+            }
+            moves.push(match c {
+                'L' => Move::Left,
+                'R' => Move::Right,
+                _ => {
+                    return Err(AocError::parse(
+                        22,
+                        path_line,
+                        path,
+                        format!("unexpected character {:?}", c),
+                    ))
+                }
+            });
+        }
+        moves.extend(steps.map(Move::Forward));
+
+        if !board.iter().any(|(_, &tile)| tile == Tile::Open) {
+            return Err(AocError::invalid(22, "no open tiles on the board"));
+        }
+        Ok(Day22 { board, path: moves })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        Ok(self.walk(|pos, facing| (self.flat_wrap(pos, facing), facing)))
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        let cube = Cube::fold(&self.board)?;
+        Ok(self.walk(|pos, facing| cube.wrap(pos, facing)))
+    }
+}
+
+impl Day22 {
+    /// Follows the path from the leftmost open tile of the top row, facing
+    /// right, and returns the password for where it ends. `wrap` gives the
+    /// position and facing after stepping off the edge of the map.
+    pub fn walk(
+        &self,
+        wrap: impl Fn(Point<usize>, Direction) -> (Point<usize>, Direction),
+    ) -> usize {
+        let mut pos = self.board.find(|&tile| tile == Tile::Open).unwrap();
+        let mut facing = Direction::Right;
+        for &step in &self.path {
+            match step {
+                Move::Left => facing = facing.turn_left(),
+                Move::Right => facing = facing.turn_right(),
+                Move::Forward(steps) => {
+                    for _ in 0..steps {
+                        let (next, next_facing) = match self.board.offset(pos, facing.delta()) {
+                            Some(next) if self.board[next] != Tile::Void => (next, facing),
+                            _ => wrap(pos, facing),
+                        };
+                        if self.board[next] == Tile::Wall {
+                            break;
+                        }
+                        (pos, facing) = (next, next_facing);
+                    }
+                }
+            }
+        }
+        let facing_score = match facing {
+            Direction::Right => 0,
+            Direction::Down => 1,
+            Direction::Left => 2,
+            Direction::Up => 3,
+        };
+        1000 * (pos.y + 1) + 4 * (pos.x + 1) + facing_score
+    }
+
+    /// Where stepping off the map from `pos` leads when the map wraps round
+    /// to the far side of the same row or column.
+    pub fn flat_wrap(&self, pos: Point<usize>, facing: Direction) -> Point<usize> {
+        self.board
+            .ray(pos, facing.opposite().delta())
+            .take_while(|&(_, &tile)| tile != Tile::Void)
+            .last()
+            .map_or(pos, |(pos, _)| pos)
+    }
+}
+
+/// How one face of the net sits on the cube: the outward normal, and the
+/// directions on the cube that going right and down on the net correspond
+/// to.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+struct Frame {
+    normal: Point3<i32>,
+    right: Point3<i32>,
+    down: Point3<i32>,
+}
+
+impl Frame {
+    /// The frame of the face next to this one on the net, in direction
+    /// `dir`, found by rolling the cube over that edge.
+    fn roll(self, dir: Direction) -> Frame {
+        let Frame {
+            normal,
+            right,
+            down,
+        } = self;
+        match dir {
+            Direction::Right => Frame {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Direction::Left => Frame {
+                normal: -right,
+                right: normal,
+                down,
+            },
+            Direction::Down => Frame {
+                normal: down,
+                right,
+                down: -normal,
+            },
+            Direction::Up => Frame {
+                normal: -down,
+                right,
+                down: normal,
+            },
+        }
+    }
+
+    /// The direction on the cube of travelling in `dir` on this face.
+    fn direction(self, dir: Direction) -> Point3<i32> {
+        match dir {
+            Direction::Right => self.right,
+            Direction::Left => -self.right,
+            Direction::Down => self.down,
+            Direction::Up => -self.down,
+        }
+    }
+}
+
+/// The board folded up into a cube.
+pub struct Cube {
+    size: usize,
+    /// Each face's frame, keyed by its position on the net (in units of faces).
+    faces: HashMap<Point<usize>, Frame>,
+}
+
+impl Cube {
+    /// Works out how the net on `board` folds into a cube, by walking from
+    /// face to face across the net and keeping track of which way each one
+    /// faces.
+    pub fn fold(board: &Grid<Tile>) -> Result<Cube, AocError> {
+        let tiles = board.iter().filter(|(_, &t)| t != Tile::Void).count();
+        let size = (1..)
+            .take_while(|s| 6 * s * s <= tiles)
+            .last()
+            .filter(|s| 6 * s * s == tiles)
+            .ok_or_else(|| AocError::invalid(22, "the board isn't the size of a cube's net"))?;
+        let net = Grid::from_vec(
+            board.width().div_ceil(size),
+            board.height().div_ceil(size),
+            (0..board.height().div_ceil(size))
+                .flat_map(|y| (0..board.width().div_ceil(size)).map(move |x| (x, y)))
+                .map(|(x, y)| {
+                    board
+                        .get(Point::new(x * size, y * size))
+                        .is_some_and(|&t| t != Tile::Void)
+                })
+                .collect(),
+        );
+        let start = net.find(|&is_face| is_face).unwrap();
+        let mut faces = HashMap::from([(
+            start,
+            Frame {
+                normal: Point3::new(0, 0, -1),
+                right: Point3::new(1, 0, 0),
+                down: Point3::new(0, 1, 0),
+            },
+        )]);
+        let mut queue = VecDeque::from([start]);
+        while let Some(face) = queue.pop_front() {
+            for dir in Direction::ALL {
+                if let Some(next) = net.offset(face, dir.delta()).filter(|&next| net[next]) {
+                    if !faces.contains_key(&next) {
+                        faces.insert(next, faces[&face].roll(dir));
+                        queue.push_back(next);
+                    }
+                }
+            }
+        }
+        let cube = Cube { size, faces };
+        let all_faces_whole = cube.faces.keys().all(|face| {
+            (0..size * size).all(|i| {
+                let pos = Point::new(face.x * size + i % size, face.y * size + i / size);
+                board.get(pos).is_some_and(|&t| t != Tile::Void)
+            })
+        });
+        let mut normals = cube.faces.values().map(|f| f.normal).collect::<Vec<_>>();
+        normals.sort_unstable();
+        normals.dedup();
+        if !all_faces_whole || cube.faces.len() != 6 || normals.len() != 6 {
+            return Err(AocError::invalid(22, "the board doesn't fold into a cube"));
+        }
+        Ok(cube)
+    }
+
+    /// Where stepping off the edge of a face from `pos`, facing `facing`,
+    /// leads on the cube, and which way it then faces.
+    pub fn wrap(&self, pos: Point<usize>, facing: Direction) -> (Point<usize>, Direction) {
+        let n = self.size;
+        let face = Point::new(pos.x / n, pos.y / n);
+        let local = Point::new(pos.x % n, pos.y % n);
+        let frame = self.faces[&face];
+        let heading = frame.direction(facing);
+        let (&next_face, &next_frame) = self
+            .faces
+            .iter()
+            .find(|(_, f)| f.normal == heading)
+            .unwrap();
+        // Having gone over the edge, we're now heading away from the old face
+        let next_facing = Direction::ALL
+            .into_iter()
+            .find(|&dir| next_frame.direction(dir) == -frame.normal)
+            .unwrap();
+        // The position along the shared edge, measured along `edge` on the cube
+        let (edge, along) = match facing {
+            Direction::Left | Direction::Right => (frame.down, local.y),
+            Direction::Up | Direction::Down => (frame.right, local.x),
+        };
+        let local_along = |dir: Direction| {
+            if next_frame.direction(dir) == edge {
+                along
+            } else {
+                n - 1 - along
+            }
+        };
+        let next_local = match next_facing {
+            Direction::Right => Point::new(0, local_along(Direction::Down)),
+            Direction::Left => Point::new(n - 1, local_along(Direction::Down)),
+            Direction::Down => Point::new(local_along(Direction::Right), 0),
+            Direction::Up => Point::new(local_along(Direction::Right), n - 1),
+        };
+        (
+            Point::new(
+                next_face.x * n + next_local.x,
+                next_face.y * n + next_local.y,
+            ),
+            next_facing,
+        )
+    }
+}
+
+pub fn day22_part1(filename: &str) -> Result<usize, AocError> {
+    day22_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day22_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day22::parse(input)?.part1()
+}
+
+pub fn day22_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day22::parse_reader(reader)?.part1()
+}
+
+pub fn day22_part2(filename: &str) -> Result<usize, AocError> {
+    day22_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day22_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day22::parse(input)?.part2()
+}
+
+pub fn day22_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day22::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day22_example_test() {
+    let input = "        ...#
+        .#..
+        #...
+        ....
+...#.......#
+........#...
+..#....#....
+..........#.
+        ...#....
+        .....#..
+        .#......
+        ......#.
+
+10R5L5R10L4R5L5
+";
+    assert_eq!(6032, day22_part1_from_str(input).unwrap());
+    assert_eq!(5031, day22_part2_from_str(input).unwrap());
+    assert!(day22_part1_from_str("\n\n10R5\n").is_err());
+
+    // From the puzzle description: A leads to B, and C to D
+    let day = Day22::parse(input).unwrap();
+    let cube = Cube::fold(&day.board).unwrap();
+    assert_eq!(
+        (Point::new(14, 8), Direction::Down),
+        cube.wrap(Point::new(11, 5), Direction::Right)
+    );
+    assert_eq!(
+        (Point::new(1, 7), Direction::Up),
+        cube.wrap(Point::new(10, 11), Direction::Down)
+    );
+
+    // Wrapping and turning round always leads straight back again, for this
+    // net and for one laid out like the real inputs
+    let other_net = "  ....\n  ....\n  ..\n  ..\n....\n....\n..\n..\n\n1\n";
+    for input in [input, other_net] {
+        let day = Day22::parse(input).unwrap();
+        let cube = Cube::fold(&day.board).unwrap();
+        for (pos, _) in day.board.iter().filter(|(_, &t)| t != Tile::Void) {
+            for facing in Direction::ALL {
+                let off_edge = day
+                    .board
+                    .offset(pos, facing.delta())
+                    .is_none_or(|next| day.board[next] == Tile::Void);
+                if off_edge {
+                    let (next, next_facing) = cube.wrap(pos, facing);
+                    assert_ne!(Tile::Void, day.board[next]);
+                    assert_eq!(
+                        (pos, facing.opposite()),
+                        cube.wrap(next, next_facing.opposite()),
+                        "{} {:?}",
+                        pos,
+                        facing
+                    );
+                }
+            }
+        }
+    }
+}
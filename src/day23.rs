@@ -0,0 +1,215 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::{AocError, Solution};
+use std::collections::{HashMap, HashSet};
+use std::fs::File;
+use std::io::Read;
+
+/// The order directions are considered in on the first round. Each round
+/// starts one further along.
+const PRIORITIES: [Direction; 4] = [
+    Direction::Up,
+    Direction::Down,
+    Direction::Left,
+    Direction::Right,
+];
+
+/// The starting positions of the elves.
+pub struct Day23 {
+    pub elves: HashSet<Point<i32>>,
+}
+
+impl Solution for Day23 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let scan = Grid::parse(input, 23, |c| match c {
+            '#' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+        let elves = scan
+            .iter()
+            .filter(|&(_, &elf)| elf)
+            .map(|(pos, _)| Point::new(pos.x as i32, pos.y as i32))
+            .collect::<HashSet<_>>();
+        if elves.is_empty() {
+            return Err(AocError::invalid(23, "there are no elves"));
+        }
+        Ok(Day23 { elves })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        let mut grove = Grove::new(self.elves.clone());
+        for _ in 0..10 {
+            grove.round();
+        }
+        Ok(grove.empty_ground())
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        let mut grove = Grove::new(self.elves.clone());
+        while grove.round() {}
+        Ok(grove.rounds())
+    }
+}
+
+/// The elves as they spread out, round by round.
+pub struct Grove {
+    elves: HashSet<Point<i32>>,
+    rounds: usize,
+}
+
+impl Grove {
+    pub fn new(elves: HashSet<Point<i32>>) -> Self {
+        Grove { elves, rounds: 0 }
+    }
+
+    pub fn elves(&self) -> &HashSet<Point<i32>> {
+        &self.elves
+    }
+
+    /// How many rounds have been run.
+    pub fn rounds(&self) -> usize {
+        self.rounds
+    }
+
+    /// Runs one round, returning whether any elf moved.
+    pub fn round(&mut self) -> bool {
+        let occupied = |p: Point<i32>| self.elves.contains(&p);
+        let proposals = self
+            .elves
+            .iter()
+            .filter_map(|&elf| {
+                let crowded = (-1..=1)
+                    .flat_map(|dy| (-1..=1).map(move |dx| Point::new(dx, dy)))
+                    .any(|step| step != Point::new(0, 0) && occupied(elf + step));
+                if !crowded {
+                    return None;
+                }
+                (0..PRIORITIES.len())
+                    .map(|i| PRIORITIES[(self.rounds + i) % PRIORITIES.len()])
+                    .find(|&dir| {
+                        // The square in that direction and both diagonals
+                        // either side of it
+                        let ahead = elf + dir.delta();
+                        [
+                            ahead,
+                            ahead + dir.turn_left().delta(),
+                            ahead + dir.turn_right().delta(),
+                        ]
+                        .into_iter()
+                        .all(|p| !occupied(p))
+                    })
+                    .map(|dir| (elf, elf + dir.delta()))
+            })
+            .collect::<Vec<_>>();
+        let mut proposed = HashMap::new();
+        for &(_, target) in &proposals {
+            *proposed.entry(target).or_insert(0) += 1;
+        }
+        let mut moved = false;
+        for (elf, target) in proposals {
+            if proposed[&target] == 1 {
+                self.elves.remove(&elf);
+                self.elves.insert(target);
+                moved = true;
+            }
+        }
+        self.rounds += 1;
+        moved
+    }
+
+    /// The smallest rectangle containing every elf, as its top left and
+    /// bottom right corners.
+    pub fn bounds(&self) -> (Point<i32>, Point<i32>) {
+        let xs = self.elves.iter().map(|e| e.x);
+        let ys = self.elves.iter().map(|e| e.y);
+        (
+            Point::new(xs.clone().min().unwrap(), ys.clone().min().unwrap()),
+            Point::new(xs.max().unwrap(), ys.max().unwrap()),
+        )
+    }
+
+    /// The number of empty tiles in the smallest rectangle containing every
+    /// elf.
+    pub fn empty_ground(&self) -> usize {
+        let (min, max) = self.bounds();
+        ((max.x - min.x + 1) * (max.y - min.y + 1)) as usize - self.elves.len()
+    }
+
+    /// Draws the smallest rectangle containing every elf, with `#` for elves
+    /// and `.` for empty ground.
+    pub fn render(&self) -> String {
+        let (min, max) = self.bounds();
+        let mut res = String::new();
+        for y in min.y..=max.y {
+            for x in min.x..=max.x {
+                res.push(if self.elves.contains(&Point::new(x, y)) {
+                    '#'
+                } else {
+                    '.'
+                });
+            }
+            res.push('\n');
+        }
+        res
+    }
+}
+
+pub fn day23_part1(filename: &str) -> Result<usize, AocError> {
+    day23_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day23_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day23::parse(input)?.part1()
+}
+
+pub fn day23_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day23::parse_reader(reader)?.part1()
+}
+
+pub fn day23_part2(filename: &str) -> Result<usize, AocError> {
+    day23_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day23_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day23::parse(input)?.part2()
+}
+
+pub fn day23_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day23::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day23_example_test() {
+    let input = "\
+....#..
+..###.#
+#...#.#
+.#...##
+#.###..
+##.#.##
+.#..#..
+";
+    assert_eq!(110, day23_part1_from_str(input).unwrap());
+    assert_eq!(20, day23_part2_from_str(input).unwrap());
+
+    let small = ".....\n..##.\n..#..\n.....\n..##.\n.....\n";
+    let mut grove = Grove::new(Day23::parse(small).unwrap().elves);
+    assert_eq!("##\n#.\n..\n##\n", grove.render());
+    let mut renders = Vec::new();
+    while grove.round() {
+        renders.push(grove.render());
+    }
+    assert_eq!(
+        vec![
+            "##\n..\n#.\n.#\n#.\n",
+            ".##.\n#...\n...#\n....\n.#..\n",
+            "..#..\n....#\n#....\n....#\n.....\n..#..\n",
+        ],
+        renders
+    );
+    assert_eq!(4, grove.rounds());
+}
@@ -0,0 +1,235 @@
+use crate::geometry::{Direction, Point};
+use crate::grid::Grid;
+use crate::search::bfs;
+use crate::{AocError, Solution};
+use std::fs::File;
+use std::io::Read;
+use std::iter;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Tile {
+    Wall,
+    Ground,
+    /// Ground with a blizzard on it at the start.
+    Blizzard(Direction),
+}
+
+/// The valley as it is at minute 0, walls included.
+pub struct Day24 {
+    pub valley: Grid<Tile>,
+    pub entrance: Point<usize>,
+    pub exit: Point<usize>,
+}
+
+impl Solution for Day24 {
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let valley = Grid::parse(input, 24, |c| match c {
+            '#' => Some(Tile::Wall),
+            '.' => Some(Tile::Ground),
+            '^' => Some(Tile::Blizzard(Direction::Up)),
+            'v' => Some(Tile::Blizzard(Direction::Down)),
+            '<' => Some(Tile::Blizzard(Direction::Left)),
+            '>' => Some(Tile::Blizzard(Direction::Right)),
+            _ => None,
+        })?;
+        let (width, height) = (valley.width(), valley.height());
+        if width < 3 || height < 3 {
+            return Err(AocError::invalid(
+                24,
+                "the valley has no room inside its walls",
+            ));
+        }
+        // The only gaps in the outer wall are one in the top row and one in
+        // the bottom row
+        let gap = |y: usize| {
+            let gaps = (0..width)
+                .map(|x| Point::new(x, y))
+                .filter(|&pos| valley[pos] != Tile::Wall)
+                .collect::<Vec<_>>();
+            match gaps[..] {
+                [pos] if valley[pos] == Tile::Ground && pos.x > 0 && pos.x < width - 1 => Ok(pos),
+                _ => Err(AocError::invalid(
+                    24,
+                    format!("row {} should have exactly one gap in its wall", y + 1),
+                )),
+            }
+        };
+        let entrance = gap(0)?;
+        let exit = gap(height - 1)?;
+        for y in 1..height - 1 {
+            if valley[Point::new(0, y)] != Tile::Wall
+                || valley[Point::new(width - 1, y)] != Tile::Wall
+            {
+                return Err(AocError::invalid(24, "the valley's side walls have gaps"));
+            }
+        }
+        // A blizzard in line with the entrance or exit would blow out of the
+        // valley instead of wrapping round
+        let escapes = valley.iter().any(|(pos, &tile)| {
+            matches!(tile, Tile::Blizzard(Direction::Up | Direction::Down))
+                && (pos.x == entrance.x || pos.x == exit.x)
+        });
+        if escapes {
+            return Err(AocError::invalid(24, "a blizzard would leave the valley"));
+        }
+        Ok(Day24 {
+            valley,
+            entrance,
+            exit,
+        })
+    }
+
+    fn part1(&self) -> Result<usize, AocError> {
+        self.trip(1)
+            .ok_or_else(|| AocError::invalid(24, "there's no way through the blizzards"))
+    }
+
+    fn part2(&self) -> Result<usize, AocError> {
+        self.trip(3)
+            .ok_or_else(|| AocError::invalid(24, "there's no way through the blizzards"))
+    }
+}
+
+impl Day24 {
+    /// The width and height of the area the blizzards move around in.
+    fn inner_size(&self) -> (usize, usize) {
+        (self.valley.width() - 2, self.valley.height() - 2)
+    }
+
+    /// How many minutes until the blizzards are all back where they started.
+    pub fn period(&self) -> usize {
+        let (width, height) = self.inner_size();
+        width / gcd(width, height) * height
+    }
+
+    /// Whether there is a blizzard on `pos` at minute `time`. Each blizzard
+    /// just wraps round its row or column, so this looks back along the row
+    /// and column for one that would have reached `pos` by now rather than
+    /// simulating them.
+    pub fn blizzard_at(&self, pos: Point<usize>, time: usize) -> bool {
+        let (width, height) = self.inner_size();
+        if pos.x == 0 || pos.y == 0 || pos.x > width || pos.y > height {
+            return false;
+        }
+        Direction::ALL.into_iter().any(|dir| {
+            let step = dir.delta::<i64>();
+            let x = (pos.x as i64 - 1 - step.x * time as i64).rem_euclid(width as i64);
+            let y = (pos.y as i64 - 1 - step.y * time as i64).rem_euclid(height as i64);
+            self.valley[Point::new(x as usize + 1, y as usize + 1)] == Tile::Blizzard(dir)
+        })
+    }
+
+    /// Whether the expedition can be on `pos` at minute `time`.
+    pub fn is_clear(&self, pos: Point<usize>, time: usize) -> bool {
+        self.valley[pos] != Tile::Wall && !self.blizzard_at(pos, time)
+    }
+
+    /// The fewest minutes it takes to get from `from` to `to`, setting off at
+    /// minute `start`. The blizzards repeat every [`Day24::period`] minutes,
+    /// so the search is over positions paired with the time modulo that.
+    pub fn crossing(&self, from: Point<usize>, to: Point<usize>, start: usize) -> Option<usize> {
+        let period = self.period();
+        bfs(
+            [(from, start % period)],
+            |&(pos, time)| {
+                let next = (time + 1) % period;
+                iter::once(pos)
+                    .chain(self.valley.neighbours4(pos))
+                    .filter(move |&pos| self.is_clear(pos, next))
+                    .map(move |pos| (pos, next))
+            },
+            |&(pos, _)| pos == to,
+        )
+        .map(|path| path.cost)
+    }
+
+    /// The fewest minutes it takes to cross the valley `legs` times, going
+    /// back and forth between the entrance and the exit. `None` if the
+    /// blizzards block the way.
+    pub fn trip(&self, legs: usize) -> Option<usize> {
+        let mut time = 0;
+        for leg in 0..legs {
+            let (from, to) = if leg % 2 == 0 {
+                (self.entrance, self.exit)
+            } else {
+                (self.exit, self.entrance)
+            };
+            time += self.crossing(from, to, time)?;
+        }
+        Some(time)
+    }
+}
+
+fn gcd(a: usize, b: usize) -> usize {
+    if b == 0 {
+        a
+    } else {
+        gcd(b, a % b)
+    }
+}
+
+pub fn day24_part1(filename: &str) -> Result<usize, AocError> {
+    day24_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day24_part1_from_str(input: &str) -> Result<usize, AocError> {
+    Day24::parse(input)?.part1()
+}
+
+pub fn day24_part1_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day24::parse_reader(reader)?.part1()
+}
+
+pub fn day24_part2(filename: &str) -> Result<usize, AocError> {
+    day24_part2_from_reader(File::open(filename)?)
+}
+
+pub fn day24_part2_from_str(input: &str) -> Result<usize, AocError> {
+    Day24::parse(input)?.part2()
+}
+
+pub fn day24_part2_from_reader(reader: impl Read) -> Result<usize, AocError> {
+    Day24::parse_reader(reader)?.part2()
+}
+
+#[test]
+fn day24_example_test() {
+    let input = "\
+#.######
+#>>.<^<#
+#.<..<<#
+#>v.><>#
+#<^v^^>#
+######.#
+";
+    assert_eq!(18, day24_part1_from_str(input).unwrap());
+    assert_eq!(54, day24_part2_from_str(input).unwrap());
+
+    let day = Day24::parse(input).unwrap();
+    assert_eq!(12, day.period());
+    assert_eq!(Some(18 + 23), day.trip(2));
+    assert_eq!(Some(0), day.trip(0));
+
+    let simple = "\
+#.#####
+#.....#
+#>....#
+#.....#
+#...v.#
+#####.#
+";
+    let day = Day24::parse(simple).unwrap();
+    assert_eq!(20, day.period());
+    assert!(day.blizzard_at(Point::new(1, 2), 0));
+    assert!(day.blizzard_at(Point::new(4, 2), 3));
+    assert!(day.blizzard_at(Point::new(1, 2), 5));
+    assert!(day.blizzard_at(Point::new(4, 1), 1));
+    assert!(!day.blizzard_at(Point::new(4, 4), 1));
+    assert!(!day.blizzard_at(day.entrance, 0));
+
+    assert!(day24_part1_from_str("#####\n#...#\n##.##\n").is_err());
+    assert!(day24_part1_from_str("#.###\n#.v.#\n###.#\n").is_err());
+}
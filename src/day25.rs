@@ -0,0 +1,305 @@
+use crate::{AocError, Solution};
+use std::error::Error;
+use std::fmt;
+use std::fs::File;
+use std::io::Read;
+use std::iter::Sum;
+use std::marker::PhantomData;
+use std::ops::Add;
+use std::str::FromStr;
+
+/// The digits of a balanced number system, in order of value from the most
+/// negative to the most positive. There must be an odd number of them, at
+/// least 3, and the middle one is zero, so a base `2k + 1` system has digits
+/// worth `-k` to `k`.
+pub trait Alphabet {
+    const DIGITS: &'static [char];
+}
+
+/// The SNAFU digits: `=` is -2, `-` is -1, then 0, 1 and 2.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct SnafuDigits;
+
+impl Alphabet for SnafuDigits {
+    const DIGITS: &'static [char] = &['=', '-', '0', '1', '2'];
+}
+
+/// A number written in a balanced base, which by default is SNAFU (balanced
+/// base 5). Other alphabets give other bases, e.g. `['T', '0', '1']` for
+/// balanced ternary.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Snafu<A: Alphabet = SnafuDigits> {
+    value: i128,
+    alphabet: PhantomData<A>,
+}
+
+impl<A: Alphabet> Snafu<A> {
+    const BASE: i128 = {
+        assert!(
+            A::DIGITS.len() % 2 == 1 && A::DIGITS.len() >= 3,
+            "a balanced base needs an odd number of digits, and at least 3"
+        );
+        A::DIGITS.len() as i128
+    };
+    /// The number of digits either side of zero.
+    const HALF: i128 = Self::BASE / 2;
+
+    pub fn new(value: i128) -> Self {
+        Snafu {
+            value,
+            alphabet: PhantomData,
+        }
+    }
+
+    pub fn value(self) -> i128 {
+        self.value
+    }
+
+    /// `None` on overflow.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        self.value.checked_add(other.value).map(Snafu::new)
+    }
+
+    /// The value of `c`, if it is a digit in this alphabet.
+    fn digit_value(c: char) -> Option<i128> {
+        let index = A::DIGITS.iter().position(|&d| d == c)?;
+        Some(index as i128 - Self::HALF)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseSnafuError(String);
+
+impl fmt::Display for ParseSnafuError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "invalid number {:?}", self.0)
+    }
+}
+
+impl Error for ParseSnafuError {}
+
+impl<A: Alphabet> FromStr for Snafu<A> {
+    type Err = ParseSnafuError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.is_empty() {
+            return Err(ParseSnafuError(s.to_string()));
+        }
+        s.chars()
+            .try_fold(0_i128, |value, c| {
+                value
+                    .checked_mul(Self::BASE)?
+                    .checked_add(Self::digit_value(c)?)
+            })
+            .map(Snafu::new)
+            .ok_or_else(|| ParseSnafuError(s.to_string()))
+    }
+}
+
+impl<A: Alphabet> fmt::Display for Snafu<A> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut value = self.value;
+        let mut digits = Vec::new();
+        loop {
+            // A remainder too big for a digit becomes a negative digit, with
+            // one carried into the next place
+            let remainder = value.rem_euclid(Self::BASE);
+            let carry = remainder > Self::HALF;
+            let digit = if carry {
+                remainder - Self::BASE
+            } else {
+                remainder
+            };
+            digits.push(A::DIGITS[(digit + Self::HALF) as usize]);
+            value = value.div_euclid(Self::BASE) + carry as i128;
+            if value == 0 {
+                break;
+            }
+        }
+        write!(f, "{}", digits.iter().rev().collect::<String>())
+    }
+}
+
+/// Panics on overflow, in release builds too. Use [`Snafu::checked_add`] to
+/// handle it instead.
+impl<A: Alphabet> Add for Snafu<A> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        self.checked_add(other)
+            .expect("overflow adding SNAFU numbers")
+    }
+}
+
+/// Panics on overflow, like [`Add`].
+impl<A: Alphabet> Sum for Snafu<A> {
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Snafu::new(0), Add::add)
+    }
+}
+
+impl<A: Alphabet> From<i64> for Snafu<A> {
+    fn from(n: i64) -> Self {
+        Snafu::new(n.into())
+    }
+}
+
+impl<A: Alphabet> From<i128> for Snafu<A> {
+    fn from(n: i128) -> Self {
+        Snafu::new(n)
+    }
+}
+
+impl<A: Alphabet> From<Snafu<A>> for i128 {
+    fn from(n: Snafu<A>) -> Self {
+        n.value
+    }
+}
+
+impl<A: Alphabet> TryFrom<Snafu<A>> for i64 {
+    type Error = std::num::TryFromIntError;
+
+    fn try_from(n: Snafu<A>) -> Result<Self, Self::Error> {
+        n.value.try_into()
+    }
+}
+
+/// The fuel requirements of each hot air balloon.
+pub struct Day25 {
+    pub numbers: Vec<Snafu>,
+}
+
+impl Solution for Day25 {
+    type Part1 = String;
+    type Part2 = String;
+
+    fn parse(input: &str) -> Result<Self, AocError> {
+        let numbers = input
+            .lines()
+            .enumerate()
+            .map(|(i, line)| {
+                line.trim()
+                    .parse::<Snafu>()
+                    .map_err(|e| AocError::parse(25, i + 1, line, e.to_string()))
+            })
+            .collect::<Result<Vec<_>, AocError>>()?;
+        Ok(Day25 { numbers })
+    }
+
+    fn part1(&self) -> Result<String, AocError> {
+        self.numbers
+            .iter()
+            .try_fold(Snafu::new(0), |total, &n| total.checked_add(n))
+            .map(|total| total.to_string())
+            .ok_or_else(|| AocError::invalid(25, "the total fuel overflows"))
+    }
+
+    /// Day 25 only has one puzzle, so its registry entry has just the one
+    /// part and the runner never asks for this.
+    fn part2(&self) -> Result<String, AocError> {
+        Err(AocError::invalid(25, "there is no part 2"))
+    }
+}
+
+pub fn day25_part1(filename: &str) -> Result<String, AocError> {
+    day25_part1_from_reader(File::open(filename)?)
+}
+
+pub fn day25_part1_from_str(input: &str) -> Result<String, AocError> {
+    Day25::parse(input)?.part1()
+}
+
+pub fn day25_part1_from_reader(reader: impl Read) -> Result<String, AocError> {
+    Day25::parse_reader(reader)?.part1()
+}
+
+#[test]
+fn day25_example_test() {
+    let input = "\
+1=-0-2
+12111
+2=0=
+21
+2=01
+111
+20012
+112
+1=-1=
+1-12
+12
+1=
+122
+";
+    assert_eq!("2=-1=0", day25_part1_from_str(input).unwrap());
+    assert!(Day25::parse(input).unwrap().part2().is_err());
+    assert!(day25_part1_from_str("12\n1x\n").is_err());
+
+    for (decimal, snafu) in [
+        (0_i64, "0"),
+        (1, "1"),
+        (3, "1="),
+        (8, "2="),
+        (10, "20"),
+        (2022, "1=11-2"),
+        (12345, "1-0---0"),
+        (314159265, "1121-1110-1=0"),
+        (-1, "-"),
+        (-3, "-2"),
+    ] {
+        assert_eq!(snafu, Snafu::<SnafuDigits>::from(decimal).to_string());
+        assert_eq!(Ok(decimal), i64::try_from(snafu.parse::<Snafu>().unwrap()));
+    }
+    assert_eq!(
+        "2=-1=0",
+        ("1".parse::<Snafu>().unwrap() + "2=-1=-".parse().unwrap()).to_string()
+    );
+    assert!("".parse::<Snafu>().is_err());
+    assert!("3".parse::<Snafu>().is_err());
+    assert!(i64::try_from(Snafu::<SnafuDigits>::from(i128::MAX)).is_err());
+
+    let max = Snafu::<SnafuDigits>::new(i128::MAX);
+    assert_eq!(None, max.checked_add(Snafu::new(1)));
+    assert_eq!(
+        Some(max),
+        Snafu::new(i128::MAX - 1).checked_add(Snafu::new(1))
+    );
+    assert!(std::panic::catch_unwind(|| max + Snafu::new(1)).is_err());
+    let huge = format!("{}\n{}\n", max, max);
+    assert!(day25_part1_from_str(&huge).is_err());
+}
+
+#[test]
+fn snafu_round_trip_test() {
+    #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+    struct Ternary;
+    impl Alphabet for Ternary {
+        const DIGITS: &'static [char] = &['T', '0', '1'];
+    }
+
+    // Every small number, then a spread of larger ones from a simple LCG
+    let mut state = 0x2545_f491_4f6c_dd1d_u64;
+    let numbers = (-1000..=1000)
+        .chain((0..1000).map(|_| {
+            state = state
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            state as i64
+        }))
+        .chain([i64::MIN, i64::MAX]);
+    for n in numbers {
+        let snafu = Snafu::<SnafuDigits>::from(n);
+        let text = snafu.to_string();
+        assert_eq!(snafu, text.parse().unwrap(), "{}", n);
+        assert_eq!(Ok(n), i64::try_from(snafu));
+        assert!(!text.starts_with('0') || n == 0);
+
+        let ternary = Snafu::<Ternary>::from(n);
+        assert_eq!(ternary, ternary.to_string().parse().unwrap(), "{}", n);
+        assert_eq!(n as i128, i128::from(ternary));
+    }
+    assert_eq!("1T0", Snafu::<Ternary>::from(6_i64).to_string());
+    for n in [i128::MAX, -i128::MAX] {
+        let snafu = Snafu::<SnafuDigits>::from(n);
+        assert_eq!(snafu, snafu.to_string().parse().unwrap());
+    }
+}
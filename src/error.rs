@@ -0,0 +1,67 @@
+use std::error::Error;
+use std::fmt;
+use std::io;
+
+/// Everything that can go wrong while solving a puzzle.
+#[derive(Debug)]
+pub enum AocError {
+    /// The input could not be read.
+    Io(io::Error),
+    /// A line of the input is malformed. `line` is 1-based.
+    Parse {
+        day: u32,
+        line: usize,
+        text: String,
+        reason: String,
+    },
+    /// The input parsed, but doesn't describe a puzzle that can be solved.
+    Invalid { day: u32, reason: String },
+}
+
+impl AocError {
+    pub fn parse(day: u32, line: usize, text: &str, reason: impl Into<String>) -> Self {
+        AocError::Parse {
+            day,
+            line,
+            text: text.to_string(),
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid(day: u32, reason: impl Into<String>) -> Self {
+        AocError::Invalid {
+            day,
+            reason: reason.into(),
+        }
+    }
+}
+
+impl fmt::Display for AocError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AocError::Io(e) => write!(f, "Could not read input: {}", e),
+            AocError::Parse {
+                day,
+                line,
+                text,
+                reason,
+            } => write!(f, "Day {}, line {}: {} ({:?})", day, line, reason, text),
+            AocError::Invalid { day, reason } => write!(f, "Day {}: {}", day, reason),
+        }
+    }
+}
+
+impl Error for AocError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            AocError::Io(e) => Some(e),
+            _ => None,
+        }
+    }
+}
+
+impl From<io::Error> for AocError {
+    fn from(e: io::Error) -> Self {
+        AocError::Io(e)
+    }
+}
@@ -0,0 +1,327 @@
+//! 2D and 3D points, and directions.
+//!
+//! Like [`Grid`](crate::grid::Grid), these use screen coordinates: `x` grows
+//! to the right and `y` grows downwards, so "up" is negative `y`.
+
+use std::error::Error;
+use std::fmt;
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+use std::str::FromStr;
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Point<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+impl<T> From<(T, T)> for Point<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Point { x, y }
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point::new(self.x + other.x, self.y + other.y)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point::new(self.x - other.x, self.y - other.y)
+    }
+}
+
+impl<T: AddAssign> AddAssign for Point<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: SubAssign> SubAssign for Point<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: Mul<Output = T> + Copy> Mul<T> for Point<T> {
+    type Output = Self;
+
+    fn mul(self, scale: T) -> Self {
+        Point::new(self.x * scale, self.y * scale)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point::new(-self.x, -self.y)
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Point3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Point3 { x, y, z }
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Point3<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Point3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Point3::new(self.x + other.x, self.y + other.y, self.z + other.z)
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Point3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Point3::new(self.x - other.x, self.y - other.y, self.z - other.z)
+    }
+}
+
+impl<T: Neg<Output = T>> Neg for Point3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Point3::new(-self.x, -self.y, -self.z)
+    }
+}
+
+macro_rules! impl_signed_point {
+    ($($t:ty)*) => {$(
+        impl Point<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs()
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                (self.x - other.x).abs().max((self.y - other.y).abs())
+            }
+
+            /// The sign of each coordinate, i.e. a step of at most 1 in each
+            /// axis towards this point from the origin.
+            pub fn signum(self) -> Self {
+                Point::new(self.x.signum(), self.y.signum())
+            }
+
+            /// Rotates 90 degrees clockwise (as seen on screen) about the origin.
+            pub fn rotate_right(self) -> Self {
+                Point::new(-self.y, self.x)
+            }
+
+            /// Rotates 90 degrees anticlockwise (as seen on screen) about the origin.
+            pub fn rotate_left(self) -> Self {
+                Point::new(self.y, -self.x)
+            }
+        }
+
+        impl Point3<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+            }
+
+            /// The 6 points that share a face with this one, as unit cubes.
+            pub fn neighbours6(self) -> [Self; 6] {
+                let Point3 { x, y, z } = self;
+                [
+                    Point3::new(x - 1, y, z),
+                    Point3::new(x + 1, y, z),
+                    Point3::new(x, y - 1, z),
+                    Point3::new(x, y + 1, z),
+                    Point3::new(x, y, z - 1),
+                    Point3::new(x, y, z + 1),
+                ]
+            }
+        }
+    )*};
+}
+
+impl_signed_point!(i8 i16 i32 i64 i128 isize);
+
+macro_rules! impl_unsigned_point {
+    ($($t:ty)*) => {$(
+        impl Point<$t> {
+            pub fn manhattan(self, other: Self) -> $t {
+                self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+            }
+
+            pub fn chebyshev(self, other: Self) -> $t {
+                self.x.abs_diff(other.x).max(self.y.abs_diff(other.y))
+            }
+        }
+    )*};
+}
+
+impl_unsigned_point!(u8 u16 u32 u64 u128 usize);
+
+impl Point<usize> {
+    /// Adds a signed offset, returning `None` if either coordinate would go
+    /// below zero.
+    pub fn checked_add_signed(self, step: Point<isize>) -> Option<Self> {
+        Some(Point::new(
+            self.x.checked_add_signed(step.x)?,
+            self.y.checked_add_signed(step.y)?,
+        ))
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Direction {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Direction {
+    /// In clockwise order, starting from `Up`.
+    pub const ALL: [Direction; 4] = [
+        Direction::Up,
+        Direction::Right,
+        Direction::Down,
+        Direction::Left,
+    ];
+
+    /// The offset of one step in this direction.
+    pub fn delta<T: From<i8>>(self) -> Point<T> {
+        let (x, y) = match self {
+            Direction::Up => (0, -1),
+            Direction::Down => (0, 1),
+            Direction::Left => (-1, 0),
+            Direction::Right => (1, 0),
+        };
+        Point::new(T::from(x), T::from(y))
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Direction::Up => Direction::Right,
+            Direction::Right => Direction::Down,
+            Direction::Down => Direction::Left,
+            Direction::Left => Direction::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        match self {
+            Direction::Up => Direction::Left,
+            Direction::Left => Direction::Down,
+            Direction::Down => Direction::Right,
+            Direction::Right => Direction::Up,
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        match self {
+            Direction::Up => Direction::Down,
+            Direction::Down => Direction::Up,
+            Direction::Left => Direction::Right,
+            Direction::Right => Direction::Left,
+        }
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseDirectionError(String);
+
+impl fmt::Display for ParseDirectionError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown direction {:?}", self.0)
+    }
+}
+
+impl Error for ParseDirectionError {}
+
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    /// Accepts `U`/`D`/`L`/`R` and the compass letters `N`/`S`/`W`/`E`.
+    fn try_from(c: char) -> Result<Self, Self::Error> {
+        match c {
+            'U' | 'N' => Ok(Direction::Up),
+            'D' | 'S' => Ok(Direction::Down),
+            'L' | 'W' => Ok(Direction::Left),
+            'R' | 'E' => Ok(Direction::Right),
+            _ => Err(ParseDirectionError(c.to_string())),
+        }
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut chars = s.chars();
+        match (chars.next(), chars.next()) {
+            (Some(c), None) => Direction::try_from(c),
+            _ => Err(ParseDirectionError(s.to_string())),
+        }
+    }
+}
+
+#[test]
+fn point_test() {
+    let a = Point::new(1_i32, -2);
+    let b = Point::new(-3, 4);
+    assert_eq!(Point::new(-2, 2), a + b);
+    assert_eq!(Point::new(4, -6), a - b);
+    assert_eq!(10, a.manhattan(b));
+    assert_eq!(6, a.chebyshev(b));
+    assert_eq!(Point::new(-1, 1), (b - a).signum());
+    assert_eq!(Point::new(2, 1), a.rotate_right());
+    assert_eq!(a, a.rotate_right().rotate_left());
+    assert_eq!(7, Point::new(5_usize, 1).manhattan(Point::new(2, 5)));
+
+    let c = Point3::new(1_i32, 2, 3);
+    assert_eq!(Point3::new(2, 4, 6), c + c);
+    assert!(c.neighbours6().iter().all(|&n| n.manhattan(c) == 1));
+}
+
+#[test]
+fn direction_test() {
+    assert_eq!(Ok(Direction::Up), "U".parse());
+    assert_eq!(Ok(Direction::Right), "E".parse());
+    assert!("UD".parse::<Direction>().is_err());
+    for d in Direction::ALL {
+        assert_eq!(
+            d.turn_right().delta::<i32>(),
+            d.delta::<i32>().rotate_right()
+        );
+        assert_eq!(d.turn_left().delta::<i32>(), d.delta::<i32>().rotate_left());
+        assert_eq!(d.opposite().delta::<i32>(), -d.delta::<i32>());
+    }
+}
@@ -0,0 +1,236 @@
+//! A dense, rectangular 2D grid.
+//!
+//! Positions are [`Point`]s, with `x` counting columns from the left and `y`
+//! counting rows from the top.
+
+use crate::geometry::Point;
+use crate::AocError;
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+/// Steps to the 4 orthogonally adjacent cells: up, down, left, right.
+pub const ORTHOGONAL: [Point<isize>; 4] = [
+    Point::new(0, -1),
+    Point::new(0, 1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+];
+
+/// Steps to all 8 adjacent cells, including diagonals.
+pub const ADJACENT: [Point<isize>; 8] = [
+    Point::new(-1, -1),
+    Point::new(0, -1),
+    Point::new(1, -1),
+    Point::new(-1, 0),
+    Point::new(1, 0),
+    Point::new(-1, 1),
+    Point::new(0, 1),
+    Point::new(1, 1),
+];
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// Builds a grid from its cells in row-major order. Panics if `width` is
+    /// 0 or doesn't match the number of cells.
+    pub fn from_vec(width: usize, height: usize, cells: Vec<T>) -> Self {
+        assert!(width > 0, "a grid must be at least one cell wide");
+        assert_eq!(width * height, cells.len());
+        Grid {
+            width,
+            height,
+            cells,
+        }
+    }
+
+    /// Parses a character map, one row per line, converting each character
+    /// with `f`. Characters that `f` rejects and ragged rows are reported as
+    /// parse errors for `day`.
+    pub fn parse(
+        input: &str,
+        day: u32,
+        mut f: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, AocError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = Vec::new();
+        for (i, line) in input.lines().enumerate() {
+            let len = line.chars().count();
+            if *width.get_or_insert(len) != len {
+                return Err(AocError::parse(day, i + 1, line, "rows differ in length"));
+            }
+            for c in line.chars() {
+                let cell = f(c).ok_or_else(|| {
+                    AocError::parse(day, i + 1, line, format!("unexpected character {:?}", c))
+                })?;
+                cells.push(cell);
+            }
+            height += 1;
+        }
+        match width {
+            Some(width) if width > 0 => Ok(Grid::from_vec(width, height, cells)),
+            _ => Err(AocError::invalid(day, "empty grid")),
+        }
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn contains(&self, pos: Point<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    pub fn get(&self, pos: Point<usize>) -> Option<&T> {
+        if self.contains(pos) {
+            Some(&self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    pub fn get_mut(&mut self, pos: Point<usize>) -> Option<&mut T> {
+        if self.contains(pos) {
+            Some(&mut self.cells[pos.y * self.width + pos.x])
+        } else {
+            None
+        }
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Point<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Point::new(x, y)))
+    }
+
+    /// Every cell along with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Point<usize>, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// The position of the first cell (in row-major order) matching `pred`.
+    pub fn find(&self, pred: impl Fn(&T) -> bool) -> Option<Point<usize>> {
+        self.iter().find(|(_, cell)| pred(cell)).map(|(pos, _)| pos)
+    }
+
+    pub fn row(&self, y: usize) -> impl DoubleEndedIterator<Item = &T> {
+        self.cells[y * self.width..(y + 1) * self.width].iter()
+    }
+
+    pub fn column(&self, x: usize) -> impl DoubleEndedIterator<Item = &T> {
+        assert!(
+            x < self.width,
+            "column {} is outside a grid {} wide",
+            x,
+            self.width
+        );
+        self.cells[x..].iter().step_by(self.width)
+    }
+
+    /// The position one `step` away from `pos`, if it is inside the grid.
+    pub fn offset(&self, pos: Point<usize>, step: Point<isize>) -> Option<Point<usize>> {
+        let pos = pos.checked_add_signed(step)?;
+        if self.contains(pos) {
+            Some(pos)
+        } else {
+            None
+        }
+    }
+
+    /// The orthogonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours4(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        ORTHOGONAL
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The orthogonal and diagonal neighbours of `pos` that are inside the grid.
+    pub fn neighbours8(&self, pos: Point<usize>) -> impl Iterator<Item = Point<usize>> + '_ {
+        ADJACENT
+            .into_iter()
+            .filter_map(move |step| self.offset(pos, step))
+    }
+
+    /// The cells seen walking from `pos` (exclusive) in steps of `step` until
+    /// the edge of the grid.
+    pub fn ray(
+        &self,
+        pos: Point<usize>,
+        step: Point<isize>,
+    ) -> impl Iterator<Item = (Point<usize>, &T)> + '_ {
+        let mut current = pos;
+        std::iter::from_fn(move || {
+            current = self.offset(current, step)?;
+            Some((current, &self[current]))
+        })
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `value`.
+    pub fn new(width: usize, height: usize, value: T) -> Self {
+        Grid::from_vec(width, height, vec![value; width * height])
+    }
+}
+
+impl<T> Index<Point<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Point<usize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T> IndexMut<Point<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Point<usize>) -> &mut T {
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{} is outside the grid", pos))
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for y in 0..self.height {
+            for cell in self.row(y) {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[test]
+fn grid_test() {
+    let input = "abc\ndef\n";
+    let grid = Grid::parse(input, 0, Some).unwrap();
+    assert_eq!((3, 2), (grid.width(), grid.height()));
+    assert_eq!('f', grid[Point::new(2, 1)]);
+    assert_eq!(input, grid.to_string());
+    assert_eq!(vec![&'b', &'e'], grid.column(1).collect::<Vec<_>>());
+    assert_eq!(vec![&'d', &'e', &'f'], grid.row(1).collect::<Vec<_>>());
+    assert_eq!(
+        vec![Point::new(0, 1), Point::new(1, 0)],
+        grid.neighbours4(Point::new(0, 0)).collect::<Vec<_>>()
+    );
+    assert_eq!(5, grid.neighbours8(Point::new(1, 0)).count());
+    assert_eq!(
+        vec![(Point::new(1, 0), &'b'), (Point::new(2, 0), &'c')],
+        grid.ray(Point::new(0, 0), Point::new(1, 0))
+            .collect::<Vec<_>>()
+    );
+    assert_eq!(Some(Point::new(1, 1)), grid.find(|&c| c == 'e'));
+    assert!(Grid::parse("ab\nc\n", 0, Some).is_err());
+    assert!(std::panic::catch_unwind(|| grid.column(5).count()).is_err());
+    assert!(std::panic::catch_unwind(|| Grid::new(0, 3, 'x')).is_err());
+}
@@ -0,0 +1,328 @@
+use aoc2022::bench::{bench_day, Report};
+use aoc2022::day01::ElfInventory;
+use aoc2022::verify::{load_answers, verify, Outcome};
+use aoc2022::{get_day, AocError, Day, DAYS};
+use std::env;
+use std::fs;
+use std::path::Path;
+use std::process;
+use std::time::{Duration, Instant};
+
+const USAGE: &str = "\
+Usage:
+    aoc2022 run <day> <part> [--input <path>]
+    aoc2022 all [--inputs <dir>]
+    aoc2022 bench [<day>] [--inputs <dir>] [--iterations <n>] [--json]
+    aoc2022 verify [--answers <path>]
+    aoc2022 inventory [--input <path>] [--percentiles <p,...>] [--buckets <n>] [--json]
+
+By default the input for day N is read from inputs/N.txt, and known answers
+are read from answers.json. Benchmarks should be run from a release build.
+The inventory command summarises the calories carried by the day 1 elves.";
+
+fn main() {
+    let args = env::args().skip(1).collect::<Vec<_>>();
+    let res = match args.iter().map(String::as_str).collect::<Vec<_>>()[..] {
+        ["run", day, part] => parse_day_part(day, part)
+            .and_then(|(day, part)| run(day, part, &default_input(day, "inputs"))),
+        ["run", day, part, "--input", input] => {
+            parse_day_part(day, part).and_then(|(day, part)| run(day, part, input))
+        }
+        ["all"] => run_all("inputs"),
+        ["all", "--inputs", dir] => run_all(dir),
+        ["bench", ref rest @ ..] => parse_bench_options(rest).and_then(|opts| run_bench(&opts)),
+        ["verify"] => run_verify("answers.json"),
+        ["verify", "--answers", path] => run_verify(path),
+        ["inventory", ref rest @ ..] => {
+            parse_inventory_options(rest).and_then(|opts| run_inventory(&opts))
+        }
+        _ => Err(USAGE.to_string()),
+    };
+    if let Err(msg) = res {
+        eprintln!("{}", msg);
+        process::exit(1);
+    }
+}
+
+fn parse_day_part(day: &str, part: &str) -> Result<(u32, u32), String> {
+    let day = day
+        .parse::<u32>()
+        .map_err(|_| format!("Invalid day: {}", day))?;
+    let part = part
+        .parse::<u32>()
+        .map_err(|_| format!("Invalid part: {}", part))?;
+    let Some(entry) = get_day(day) else {
+        return Err(format!("Day {} is not implemented", day));
+    };
+    if part != 1 && part != 2 {
+        return Err(format!("Part must be 1 or 2, got {}", part));
+    }
+    if part > entry.parts {
+        return Err(format!("Day {} has no part {}", day, part));
+    }
+    Ok((day, part))
+}
+
+fn default_input(day: u32, dir: &str) -> String {
+    Path::new(dir)
+        .join(format!("{}.txt", day))
+        .to_string_lossy()
+        .into_owned()
+}
+
+fn run(day: u32, part: u32, input: &str) -> Result<(), String> {
+    if !Path::new(input).is_file() {
+        return Err(format!("Input file {} does not exist", input));
+    }
+    let (answer, elapsed) = solve(day, part, input).map_err(|e| e.to_string())?;
+    print_answer(day, part, &answer, elapsed);
+    Ok(())
+}
+
+fn run_all(dir: &str) -> Result<(), String> {
+    let mut total = Duration::ZERO;
+    for &Day { day, parts, .. } in DAYS {
+        let input = default_input(day, dir);
+        if !Path::new(&input).is_file() {
+            println!("Day {:>2}: skipped, {} not found", day, input);
+            continue;
+        }
+        for part in 1..=parts {
+            match solve(day, part, &input) {
+                Ok((answer, elapsed)) => {
+                    print_answer(day, part, &answer, elapsed);
+                    total += elapsed;
+                }
+                Err(e) => println!("Day {:>2} part {}: error: {}", day, part, e),
+            }
+        }
+    }
+    println!("Total: {:?}", total);
+    Ok(())
+}
+
+struct BenchOptions<'a> {
+    day: Option<u32>,
+    inputs: &'a str,
+    iterations: usize,
+    json: bool,
+}
+
+fn parse_bench_options<'a>(args: &[&'a str]) -> Result<BenchOptions<'a>, String> {
+    let mut opts = BenchOptions {
+        day: None,
+        inputs: "inputs",
+        iterations: 10,
+        json: false,
+    };
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        match arg {
+            "--inputs" => opts.inputs = args.next().ok_or(USAGE)?,
+            "--iterations" => {
+                let n = args.next().ok_or(USAGE)?;
+                opts.iterations = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid iteration count: {}", n)),
+                };
+            }
+            "--json" => opts.json = true,
+            day if opts.day.is_none() && !day.starts_with("--") => {
+                let day = day
+                    .parse::<u32>()
+                    .map_err(|_| format!("Invalid day: {}", day))?;
+                if get_day(day).is_none() {
+                    return Err(format!("Day {} is not implemented", day));
+                }
+                opts.day = Some(day);
+            }
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(opts)
+}
+
+fn run_bench(opts: &BenchOptions) -> Result<(), String> {
+    let mut report = Report {
+        iterations: opts.iterations,
+        days: Vec::new(),
+    };
+    for day in DAYS.iter().filter(|d| opts.day.is_none_or(|n| n == d.day)) {
+        let input = default_input(day.day, opts.inputs);
+        let contents = match fs::read_to_string(&input) {
+            Ok(contents) => contents,
+            Err(_) if opts.day.is_none() => {
+                if !opts.json {
+                    println!("Day {:>2}: skipped, {} not found", day.day, input);
+                }
+                continue;
+            }
+            Err(e) => return Err(format!("Could not read {}: {}", input, e)),
+        };
+        let day_report = match bench_day(day, &input, &contents, opts.iterations) {
+            Ok(day_report) => day_report,
+            Err(e) if opts.day.is_none() => {
+                if !opts.json {
+                    println!("Day {:>2}: error: {}", day.day, e);
+                }
+                continue;
+            }
+            Err(e) => return Err(e.to_string()),
+        };
+        if !opts.json {
+            print!(
+                "Day {:>2}: parse {:>12?}",
+                day.day,
+                day_report.parse.median()
+            );
+            for part in &day_report.parts {
+                match part.solve {
+                    Some(timing) => print!("  part {} {:>12?}", part.part, timing.median()),
+                    None => print!("  part {} {:>12}", part.part, "error"),
+                }
+            }
+            println!();
+        }
+        report.days.push(day_report);
+    }
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        println!("(median of {} iterations)", opts.iterations);
+    }
+    Ok(())
+}
+
+fn run_verify(path: &str) -> Result<(), String> {
+    let answers = load_answers(path).map_err(|e| format!("Could not load {}: {}", path, e))?;
+    let base = Path::new(path).parent().unwrap_or(Path::new(""));
+    let checks = verify(&answers, base);
+    let (mut passed, mut mismatched, mut failed) = (0, 0, 0);
+    for check in &checks {
+        let label = format!(
+            "Day {:>2} part {} {} ({:?})",
+            check.day, check.part, check.input, check.elapsed
+        );
+        match &check.outcome {
+            Outcome::Pass => {
+                passed += 1;
+                println!("{}: pass", label);
+            }
+            Outcome::Mismatch { expected, actual } => {
+                mismatched += 1;
+                if expected.contains('\n') || actual.contains('\n') {
+                    println!(
+                        "{}: MISMATCH\nexpected:\n{}\ngot:\n{}",
+                        label, expected, actual
+                    );
+                } else {
+                    println!("{}: MISMATCH, expected {}, got {}", label, expected, actual);
+                }
+            }
+            Outcome::Fail(e) => {
+                failed += 1;
+                println!("{}: FAIL: {}", label, e);
+            }
+        }
+    }
+    println!(
+        "{} passed, {} mismatched, {} failed",
+        passed, mismatched, failed
+    );
+    if passed == checks.len() {
+        Ok(())
+    } else {
+        Err(format!(
+            "{} of {} checks did not pass",
+            checks.len() - passed,
+            checks.len()
+        ))
+    }
+}
+
+struct InventoryOptions<'a> {
+    input: &'a str,
+    percentiles: Vec<f64>,
+    buckets: usize,
+    json: bool,
+}
+
+fn parse_inventory_options<'a>(args: &[&'a str]) -> Result<InventoryOptions<'a>, String> {
+    let mut opts = InventoryOptions {
+        input: "inputs/1.txt",
+        percentiles: vec![10.0, 25.0, 75.0, 90.0],
+        buckets: 10,
+        json: false,
+    };
+    let mut args = args.iter();
+    while let Some(&arg) = args.next() {
+        match arg {
+            "--input" => opts.input = args.next().ok_or(USAGE)?,
+            "--percentiles" => {
+                let list = args.next().ok_or(USAGE)?;
+                opts.percentiles = list
+                    .split(',')
+                    .map(|p| match p.trim().parse() {
+                        Ok(p) if (0.0..=100.0).contains(&p) => Ok(p),
+                        _ => Err(format!("Invalid percentile: {}", p)),
+                    })
+                    .collect::<Result<_, _>>()?;
+            }
+            "--buckets" => {
+                let n = args.next().ok_or(USAGE)?;
+                opts.buckets = match n.parse() {
+                    Ok(n) if n > 0 => n,
+                    _ => return Err(format!("Invalid bucket count: {}", n)),
+                };
+            }
+            "--json" => opts.json = true,
+            _ => return Err(USAGE.to_string()),
+        }
+    }
+    Ok(opts)
+}
+
+fn run_inventory(opts: &InventoryOptions) -> Result<(), String> {
+    let file =
+        fs::File::open(opts.input).map_err(|e| format!("Could not read {}: {}", opts.input, e))?;
+    let inventory = ElfInventory::from_reader(file).map_err(|e| e.to_string())?;
+    let report = inventory.report(&opts.percentiles, opts.buckets);
+    if opts.json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+        return Ok(());
+    }
+    let stat = |stat: Option<f64>| stat.map_or("-".to_string(), |n| format!("{:.1}", n));
+    println!("{:<14} {:>10}", "Elves", report.count);
+    println!("{:<14} {:>10}", "Mean", stat(report.mean));
+    println!("{:<14} {:>10}", "Median", stat(report.median));
+    for p in &report.percentiles {
+        let label = format!("Percentile {}", p.percentile);
+        println!("{:<14} {:>10}", label, stat(Some(p.calories)));
+    }
+    if report.count > 0 {
+        println!();
+        print!("{}", inventory.histogram(opts.buckets, 40));
+    }
+    Ok(())
+}
+
+fn print_answer(day: u32, part: u32, answer: &str, elapsed: Duration) {
+    if answer.contains('\n') {
+        // Multi-line answers (e.g. the day 10 CRT) are printed on their own lines
+        println!("Day {:>2} part {} ({:?}):\n{}", day, part, elapsed, answer);
+    } else {
+        println!("Day {:>2} part {} ({:?}): {}", day, part, elapsed, answer);
+    }
+}
+
+fn solve(day: u32, part: u32, input: &str) -> Result<(String, Duration), AocError> {
+    let contents = fs::read_to_string(input)?;
+    let start = Instant::now();
+    let solution = (get_day(day).unwrap().parse)(&contents)?;
+    let answer = match part {
+        1 => solution.part1_answer()?,
+        2 => solution.part2_answer()?,
+        _ => unreachable!(),
+    };
+    Ok((answer.to_string(), start.elapsed()))
+}
@@ -0,0 +1,202 @@
+//! Shortest path searches and flood fill over implicit graphs.
+//!
+//! The graph is described by a `neighbours` closure rather than built up
+//! front, and the path searches stop at the first node accepted by `is_goal`.
+//! All of them accept several start nodes, which behaves like a single start
+//! with a free edge to each of them.
+
+use std::cmp::Reverse;
+use std::collections::hash_map::Entry;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+
+/// A shortest path and its total cost. `nodes` runs from one of the start
+/// nodes to the goal, inclusive.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Path<N, C> {
+    pub cost: C,
+    pub nodes: Vec<N>,
+}
+
+/// Breadth-first search where every edge costs 1.
+pub fn bfs<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, usize>>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut parents: HashMap<N, Option<N>> = HashMap::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if let Entry::Vacant(entry) = parents.entry(start.clone()) {
+            entry.insert(None);
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        if is_goal(&node) {
+            let mut nodes = vec![node];
+            while let Some(Some(parent)) = parents.get(nodes.last().unwrap()) {
+                nodes.push(parent.clone());
+            }
+            nodes.reverse();
+            return Some(Path {
+                cost: nodes.len() - 1,
+                nodes,
+            });
+        }
+        for next in neighbours(&node) {
+            if let Entry::Vacant(entry) = parents.entry(next.clone()) {
+                entry.insert(Some(node.clone()));
+                queue.push_back(next);
+            }
+        }
+    }
+    None
+}
+
+/// Every node reachable from `starts`, including the starts themselves.
+pub fn flood_fill<N, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+) -> HashSet<N>
+where
+    N: Clone + Eq + Hash,
+    I: IntoIterator<Item = N>,
+{
+    let mut seen = HashSet::new();
+    let mut queue = VecDeque::new();
+    for start in starts {
+        if seen.insert(start.clone()) {
+            queue.push_back(start);
+        }
+    }
+    while let Some(node) = queue.pop_front() {
+        for next in neighbours(&node) {
+            if seen.insert(next.clone()) {
+                queue.push_back(next);
+            }
+        }
+    }
+    seen
+}
+
+/// Dijkstra's algorithm, for graphs where `neighbours` yields each
+/// neighbour along with the cost of the edge to it.
+pub fn dijkstra<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    neighbours: impl FnMut(&N) -> I,
+    is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// A* search. `heuristic` estimates the remaining cost from a node to the
+/// nearest goal, and must never overestimate it for the result to be a
+/// shortest path.
+pub fn astar<N, C, I>(
+    starts: impl IntoIterator<Item = N>,
+    mut neighbours: impl FnMut(&N) -> I,
+    mut heuristic: impl FnMut(&N) -> C,
+    mut is_goal: impl FnMut(&N) -> bool,
+) -> Option<Path<N, C>>
+where
+    N: Clone + Eq + Hash,
+    C: Copy + Ord + Add<Output = C> + Default,
+    I: IntoIterator<Item = (N, C)>,
+{
+    // Every node pushed onto the heap, with the index of the node it was
+    // reached from, so the heap itself only has to order plain indices
+    let mut visits: Vec<(N, Option<usize>)> = Vec::new();
+    let mut best_costs = HashMap::new();
+    let mut heap = BinaryHeap::new();
+    for start in starts {
+        if best_costs.insert(start.clone(), C::default()).is_none() {
+            heap.push(Reverse((heuristic(&start), C::default(), visits.len())));
+            visits.push((start, None));
+        }
+    }
+    while let Some(Reverse((_, cost, index))) = heap.pop() {
+        let node = visits[index].0.clone();
+        if best_costs[&node] < cost {
+            // Already reached more cheaply since this entry was pushed
+            continue;
+        }
+        if is_goal(&node) {
+            let mut nodes = Vec::new();
+            let mut current = Some(index);
+            while let Some(i) = current {
+                nodes.push(visits[i].0.clone());
+                current = visits[i].1;
+            }
+            nodes.reverse();
+            return Some(Path { cost, nodes });
+        }
+        for (next, step_cost) in neighbours(&node) {
+            let next_cost = cost + step_cost;
+            if best_costs.get(&next).is_none_or(|&best| next_cost < best) {
+                best_costs.insert(next.clone(), next_cost);
+                heap.push(Reverse((
+                    next_cost + heuristic(&next),
+                    next_cost,
+                    visits.len(),
+                )));
+                visits.push((next, Some(index)));
+            }
+        }
+    }
+    None
+}
+
+#[test]
+fn search_test() {
+    use crate::geometry::Point;
+    use crate::grid::Grid;
+
+    let maze = Grid::parse("..#.\n.##.\n....\n", 0, |c| Some(c == '#')).unwrap();
+    let open = |pos: &Point<usize>| {
+        maze.neighbours4(*pos)
+            .filter(|&next| !maze[next])
+            .collect::<Vec<_>>()
+    };
+    let start = Point::new(0, 0);
+    let goal = Point::new(3, 0);
+
+    let path = bfs([start], open, |&pos| pos == goal).unwrap();
+    assert_eq!(7, path.cost);
+    assert_eq!(8, path.nodes.len());
+    assert_eq!((start, goal), (path.nodes[0], path.nodes[7]));
+    assert!(path
+        .nodes
+        .windows(2)
+        .all(|step| step[0].manhattan(step[1]) == 1));
+
+    // Extra starts are fine, and the nearest one wins
+    let path = bfs([start, Point::new(3, 2)], open, |&pos| pos == goal).unwrap();
+    assert_eq!(2, path.cost);
+
+    let weighted = |pos: &Point<usize>| open(pos).into_iter().map(|next| (next, 2));
+    let path = dijkstra([start], weighted, |&pos| pos == goal).unwrap();
+    assert_eq!(14, path.cost);
+    let path = astar(
+        [start],
+        weighted,
+        |pos| 2 * pos.manhattan(goal),
+        |&pos| pos == goal,
+    )
+    .unwrap();
+    assert_eq!(14, path.cost);
+    assert_eq!(8, path.nodes.len());
+
+    assert_eq!(None, bfs([start], open, |&pos| pos == Point::new(2, 0)));
+    assert_eq!(9, flood_fill([start], open).len());
+}
@@ -0,0 +1,259 @@
+use crate::AocError;
+use std::fmt;
+use std::io::Read;
+
+/// The answer to one part of a puzzle.
+///
+/// Each day returns whichever type is most natural for it, and converts into
+/// an `Answer` so that answers from different days can be handled uniformly.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u64),
+    Signed(i64),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(n) => write!(f, "{}", n),
+            Answer::Signed(n) => write!(f, "{}", n),
+            Answer::Text(s) => write!(f, "{}", s),
+        }
+    }
+}
+
+impl From<u64> for Answer {
+    fn from(n: u64) -> Self {
+        Answer::Unsigned(n)
+    }
+}
+
+impl From<u32> for Answer {
+    fn from(n: u32) -> Self {
+        Answer::Unsigned(n.into())
+    }
+}
+
+impl From<usize> for Answer {
+    fn from(n: usize) -> Self {
+        Answer::Unsigned(n as u64)
+    }
+}
+
+impl From<i64> for Answer {
+    fn from(n: i64) -> Self {
+        Answer::Signed(n)
+    }
+}
+
+impl From<i32> for Answer {
+    fn from(n: i32) -> Self {
+        Answer::Signed(n.into())
+    }
+}
+
+impl From<String> for Answer {
+    fn from(s: String) -> Self {
+        Answer::Text(s)
+    }
+}
+
+/// A day's puzzle: the input is parsed once, and both parts are then solved
+/// from the parsed representation.
+pub trait Solution {
+    type Part1: Into<Answer>;
+    type Part2: Into<Answer>;
+
+    fn parse(input: &str) -> Result<Self, AocError>
+    where
+        Self: Sized;
+
+    /// Reads the whole input from `reader` and parses it.
+    fn parse_reader(mut reader: impl Read) -> Result<Self, AocError>
+    where
+        Self: Sized,
+    {
+        let mut input = String::new();
+        reader.read_to_string(&mut input)?;
+        Self::parse(&input)
+    }
+
+    fn part1(&self) -> Result<Self::Part1, AocError>;
+    fn part2(&self) -> Result<Self::Part2, AocError>;
+}
+
+/// Object-safe view of a parsed [`Solution`], so that different days can be
+/// stored side by side in the registry.
+pub trait DynSolution {
+    fn part1_answer(&self) -> Result<Answer, AocError>;
+    fn part2_answer(&self) -> Result<Answer, AocError>;
+}
+
+impl<S: Solution> DynSolution for S {
+    fn part1_answer(&self) -> Result<Answer, AocError> {
+        self.part1().map(Into::into)
+    }
+
+    fn part2_answer(&self) -> Result<Answer, AocError> {
+        self.part2().map(Into::into)
+    }
+}
+
+/// An entry in the registry of implemented days.
+pub struct Day {
+    pub day: u32,
+    /// How many parts the puzzle has. Day 25 only has the one.
+    pub parts: u32,
+    pub parse: fn(&str) -> Result<Box<dyn DynSolution>, AocError>,
+}
+
+fn parse_boxed<S: Solution + 'static>(input: &str) -> Result<Box<dyn DynSolution>, AocError> {
+    Ok(Box::new(S::parse(input)?))
+}
+
+/// Every implemented day, in order.
+pub const DAYS: &[Day] = &[
+    Day {
+        day: 1,
+        parts: 2,
+        parse: parse_boxed::<crate::day01::Day1>,
+    },
+    Day {
+        day: 2,
+        parts: 2,
+        parse: parse_boxed::<crate::day02::Day2>,
+    },
+    Day {
+        day: 3,
+        parts: 2,
+        parse: parse_boxed::<crate::day03::Day3>,
+    },
+    Day {
+        day: 4,
+        parts: 2,
+        parse: parse_boxed::<crate::day04::Day4>,
+    },
+    Day {
+        day: 5,
+        parts: 2,
+        parse: parse_boxed::<crate::day05::Day5>,
+    },
+    Day {
+        day: 6,
+        parts: 2,
+        parse: parse_boxed::<crate::day06::Day6>,
+    },
+    Day {
+        day: 7,
+        parts: 2,
+        parse: parse_boxed::<crate::day07::Day7>,
+    },
+    Day {
+        day: 8,
+        parts: 2,
+        parse: parse_boxed::<crate::day08::Day8>,
+    },
+    Day {
+        day: 9,
+        parts: 2,
+        parse: parse_boxed::<crate::day09::Day9>,
+    },
+    Day {
+        day: 10,
+        parts: 2,
+        parse: parse_boxed::<crate::day10::Day10>,
+    },
+    Day {
+        day: 11,
+        parts: 2,
+        parse: parse_boxed::<crate::day11::Day11>,
+    },
+    Day {
+        day: 12,
+        parts: 2,
+        parse: parse_boxed::<crate::day12::Day12>,
+    },
+    Day {
+        day: 13,
+        parts: 2,
+        parse: parse_boxed::<crate::day13::Day13>,
+    },
+    Day {
+        day: 14,
+        parts: 2,
+        parse: parse_boxed::<crate::day14::Day14>,
+    },
+    Day {
+        day: 15,
+        parts: 2,
+        parse: parse_boxed::<crate::day15::Day15>,
+    },
+    Day {
+        day: 16,
+        parts: 2,
+        parse: parse_boxed::<crate::day16::Day16>,
+    },
+    Day {
+        day: 17,
+        parts: 2,
+        parse: parse_boxed::<crate::day17::Day17>,
+    },
+    Day {
+        day: 18,
+        parts: 2,
+        parse: parse_boxed::<crate::day18::Day18>,
+    },
+    Day {
+        day: 19,
+        parts: 2,
+        parse: parse_boxed::<crate::day19::Day19>,
+    },
+    Day {
+        day: 20,
+        parts: 2,
+        parse: parse_boxed::<crate::day20::Day20>,
+    },
+    Day {
+        day: 21,
+        parts: 2,
+        parse: parse_boxed::<crate::day21::Day21>,
+    },
+    Day {
+        day: 22,
+        parts: 2,
+        parse: parse_boxed::<crate::day22::Day22>,
+    },
+    Day {
+        day: 23,
+        parts: 2,
+        parse: parse_boxed::<crate::day23::Day23>,
+    },
+    Day {
+        day: 24,
+        parts: 2,
+        parse: parse_boxed::<crate::day24::Day24>,
+    },
+    Day {
+        day: 25,
+        parts: 1,
+        parse: parse_boxed::<crate::day25::Day25>,
+    },
+];
+
+/// Looks up a day in the registry.
+pub fn get_day(day: u32) -> Option<&'static Day> {
+    DAYS.iter().find(|d| d.day == day)
+}
+
+#[test]
+fn registry_test() {
+    assert!(DAYS.windows(2).all(|w| w[0].day < w[1].day));
+    let input = std::fs::read_to_string("inputs/7.txt").unwrap();
+    let solution = (get_day(7).unwrap().parse)(&input).unwrap();
+    assert_eq!(Answer::Unsigned(1141028), solution.part1_answer().unwrap());
+    assert_eq!("8278005", solution.part2_answer().unwrap().to_string());
+    assert!(get_day(0).is_none());
+    assert!(DAYS.iter().all(|d| d.parts == 2 || d.day == 25));
+    assert_eq!(1, get_day(25).unwrap().parts);
+}
@@ -0,0 +1,192 @@
+//! Checking the solvers against a manifest of known answers.
+//!
+//! The manifest is a JSON list of entries like
+//! `{"day": 1, "input": "inputs/1.txt", "part1": "69693", "part2": "200945"}`.
+//! Input paths are relative to the manifest, either part may be left out if
+//! its answer isn't known, and a day may appear any number of times with
+//! different inputs.
+
+use crate::get_day;
+use serde::Deserialize;
+use std::error::Error;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+#[derive(Clone, Debug, PartialEq, Eq, Deserialize)]
+pub struct KnownAnswers {
+    pub day: u32,
+    pub input: String,
+    #[serde(default)]
+    pub part1: Option<String>,
+    #[serde(default)]
+    pub part2: Option<String>,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Outcome {
+    Pass,
+    Mismatch {
+        expected: String,
+        actual: String,
+    },
+    /// The input couldn't be read or parsed, or the solver returned an error.
+    Fail(String),
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Check {
+    pub day: u32,
+    pub part: u32,
+    pub input: String,
+    pub outcome: Outcome,
+    /// Time taken to parse the input and solve the part.
+    pub elapsed: Duration,
+}
+
+/// Why the manifest of known answers couldn't be loaded.
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(io::Error),
+    /// The manifest isn't valid JSON, or doesn't match [`KnownAnswers`].
+    Json(serde_json::Error),
+}
+
+impl fmt::Display for ManifestError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ManifestError::Io(e) => write!(f, "could not read answers manifest: {}", e),
+            ManifestError::Json(e) => write!(f, "invalid answers manifest: {}", e),
+        }
+    }
+}
+
+impl Error for ManifestError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            ManifestError::Io(e) => Some(e),
+            ManifestError::Json(e) => Some(e),
+        }
+    }
+}
+
+pub fn parse_answers(manifest: &str) -> Result<Vec<KnownAnswers>, ManifestError> {
+    serde_json::from_str(manifest).map_err(ManifestError::Json)
+}
+
+/// Reads the manifest at `path`.
+pub fn load_answers(path: impl AsRef<Path>) -> Result<Vec<KnownAnswers>, ManifestError> {
+    parse_answers(&fs::read_to_string(path).map_err(ManifestError::Io)?)
+}
+
+/// Runs every known answer in `answers`, resolving inputs relative to `base`.
+pub fn verify(answers: &[KnownAnswers], base: &Path) -> Vec<Check> {
+    answers
+        .iter()
+        .flat_map(|entry| verify_entry(entry, base))
+        .collect()
+}
+
+fn verify_entry(entry: &KnownAnswers, base: &Path) -> Vec<Check> {
+    let expected = [(1, &entry.part1), (2, &entry.part2)]
+        .into_iter()
+        .filter_map(|(part, answer)| Some((part, answer.as_ref()?)))
+        .collect::<Vec<_>>();
+    let check = |part, outcome, elapsed| Check {
+        day: entry.day,
+        part,
+        input: entry.input.clone(),
+        outcome,
+        elapsed,
+    };
+    let fail_all = |reason: String| {
+        expected
+            .iter()
+            .map(|&(part, _)| check(part, Outcome::Fail(reason.clone()), Duration::ZERO))
+            .collect()
+    };
+
+    let Some(day) = get_day(entry.day) else {
+        return fail_all(format!("day {} is not implemented", entry.day));
+    };
+    let contents = match fs::read_to_string(base.join(&entry.input)) {
+        Ok(contents) => contents,
+        Err(e) => return fail_all(format!("could not read {}: {}", entry.input, e)),
+    };
+    let start = Instant::now();
+    let solution = match (day.parse)(&contents) {
+        Ok(solution) => solution,
+        Err(e) => return fail_all(e.to_string()),
+    };
+    let parse_time = start.elapsed();
+
+    expected
+        .iter()
+        .map(|&(part, expected)| {
+            let start = Instant::now();
+            let answer = match part {
+                1 => solution.part1_answer(),
+                _ => solution.part2_answer(),
+            };
+            let elapsed = parse_time + start.elapsed();
+            let outcome = match answer {
+                Ok(answer) if answer.to_string() == *expected => Outcome::Pass,
+                Ok(answer) => Outcome::Mismatch {
+                    expected: expected.clone(),
+                    actual: answer.to_string(),
+                },
+                Err(e) => Outcome::Fail(e.to_string()),
+            };
+            check(part, outcome, elapsed)
+        })
+        .collect()
+}
+
+#[test]
+fn verify_test() {
+    let answers = load_answers("answers.json").unwrap();
+    let checks = verify(&answers, Path::new("."));
+    assert_eq!(28, checks.len());
+    assert!(checks.iter().all(|c| c.outcome == Outcome::Pass));
+
+    let answers = parse_answers(
+        r#"[
+            {"day": 7, "input": "inputs/7.txt", "part1": "1", "part2": "8278005"},
+            {"day": 7, "input": "inputs/missing.txt", "part1": "1141028"},
+            {"day": 99, "input": "inputs/7.txt", "part2": "0"}
+        ]"#,
+    )
+    .unwrap();
+    let outcomes = verify(&answers, Path::new("."))
+        .into_iter()
+        .map(|c| (c.day, c.part, c.outcome))
+        .collect::<Vec<_>>();
+    assert_eq!(4, outcomes.len());
+    assert_eq!(
+        (
+            7,
+            1,
+            Outcome::Mismatch {
+                expected: "1".to_string(),
+                actual: "1141028".to_string()
+            }
+        ),
+        outcomes[0]
+    );
+    assert_eq!((7, 2, Outcome::Pass), outcomes[1]);
+    assert!(matches!(outcomes[2], (7, 1, Outcome::Fail(_))));
+    assert!(matches!(outcomes[3], (99, 2, Outcome::Fail(_))));
+
+    assert!(matches!(
+        parse_answers(r#"[{"day": 1}]"#),
+        Err(ManifestError::Json(_))
+    ));
+    let err = parse_answers("[{").unwrap_err();
+    assert!(err.to_string().starts_with("invalid answers manifest: "));
+    assert!(matches!(
+        load_answers("missing.json"),
+        Err(ManifestError::Io(_))
+    ));
+}